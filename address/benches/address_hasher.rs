@@ -0,0 +1,82 @@
+use {
+    criterion::{criterion_group, criterion_main, Criterion},
+    rand::random,
+    solana_address::{Address, AddressHashMap, AddressHasherBuilder},
+    std::{collections::HashMap, hint::black_box},
+};
+
+const NUM_KEYS: usize = 10_000;
+
+// Random keys rather than `Address::new_unique()`, whose leading counter bytes
+// are mostly zero and would make some hasher offsets collide heavily
+fn random_keys() -> Vec<Address> {
+    (0..NUM_KEYS)
+        .map(|_| Address::new_from_array(random()))
+        .collect()
+}
+
+// Benchmark for lookups in maps using the default hasher and AddressHasher
+fn bench_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("address_hash_map_lookup");
+    let keys = random_keys();
+
+    let default_map: HashMap<Address, usize> = keys
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(i, key)| (key, i))
+        .collect();
+    group.bench_function("default_hasher", |b| {
+        b.iter(|| {
+            for key in &keys {
+                black_box(default_map.get(key));
+            }
+        });
+    });
+
+    let address_map: AddressHashMap<usize> = keys
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(i, key)| (key, i))
+        .collect();
+    group.bench_function("address_hasher", |b| {
+        b.iter(|| {
+            for key in &keys {
+                black_box(address_map.get(key));
+            }
+        });
+    });
+    group.finish();
+}
+
+// Benchmark for filling maps using the default hasher and AddressHasher
+fn bench_insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("address_hash_map_insert");
+    let keys = random_keys();
+
+    group.bench_function("default_hasher", |b| {
+        b.iter(|| {
+            let mut map = HashMap::with_capacity(NUM_KEYS);
+            for (i, key) in keys.iter().cloned().enumerate() {
+                map.insert(key, i);
+            }
+            black_box(map)
+        });
+    });
+
+    group.bench_function("address_hasher", |b| {
+        b.iter(|| {
+            let mut map =
+                AddressHashMap::with_capacity_and_hasher(NUM_KEYS, AddressHasherBuilder::default());
+            for (i, key) in keys.iter().cloned().enumerate() {
+                map.insert(key, i);
+            }
+            black_box(map)
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_lookup, bench_insert);
+criterion_main!(benches);
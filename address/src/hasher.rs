@@ -1,5 +1,5 @@
 use {
-    crate::ADDRESS_BYTES,
+    crate::{Address, ADDRESS_BYTES},
     core::{
         cell::Cell,
         hash::{BuildHasher, Hasher},
@@ -91,10 +91,16 @@ impl BuildHasher for AddressHasherBuilder {
     }
 }
 
+/// A `HashMap` keyed by [`Address`] that uses [`AddressHasherBuilder`].
+///
+/// The same caveat applies: collisions are cheap to produce, so don't use
+/// this for maps whose keys an attacker controls.
+pub type AddressHashMap<V> = std::collections::HashMap<Address, V, AddressHasherBuilder>;
+
 #[cfg(test)]
 mod tests {
     use {
-        super::AddressHasherBuilder,
+        super::{AddressHashMap, AddressHasherBuilder},
         crate::Address,
         core::hash::{BuildHasher, Hasher},
     };
@@ -137,4 +143,23 @@ mod tests {
         hasher2.write(key2.as_array());
         assert_ne!(hasher1.finish(), hasher2.finish());
     }
+
+    #[test]
+    fn test_address_hash_map() {
+        let keys: std::vec::Vec<Address> = (0..64).map(|_| Address::new_unique()).collect();
+        let mut map = AddressHashMap::default();
+        for (i, key) in keys.iter().cloned().enumerate() {
+            assert_eq!(map.insert(key, i), None);
+        }
+        assert_eq!(map.len(), keys.len());
+        for (i, key) in keys.iter().enumerate() {
+            assert_eq!(map.get(key), Some(&i));
+        }
+        let first = keys.first().cloned().unwrap();
+        assert_eq!(map.insert(first, 100), Some(0));
+        assert_eq!(map.remove(&keys[1]), Some(1));
+        assert_eq!(map.get(&keys[1]), None);
+        assert_eq!(map.get(&Address::new_unique()), None);
+        assert_eq!(map.len(), keys.len() - 1);
+    }
 }
@@ -12,6 +12,8 @@
 pub mod error;
 #[cfg(feature = "rand")]
 mod hasher;
+#[cfg(all(feature = "serde", feature = "decode"))]
+pub mod serde_str;
 #[cfg(any(feature = "curve25519", feature = "syscalls"))]
 pub mod syscalls;
 
@@ -20,7 +22,7 @@ use crate::error::AddressError;
 #[cfg(feature = "decode")]
 use crate::error::ParseAddressError;
 #[cfg(all(feature = "rand", not(any(target_os = "solana", target_arch = "bpf"))))]
-pub use crate::hasher::{AddressHasher, AddressHasherBuilder};
+pub use crate::hasher::{AddressHashMap, AddressHasher, AddressHasherBuilder};
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -38,6 +40,7 @@ use core::{
     array,
     convert::TryFrom,
     hash::{Hash, Hasher},
+    ops::{BitXor, BitXorAssign},
     ptr::read_unaligned,
 };
 #[cfg(feature = "serde")]
@@ -56,7 +59,12 @@ pub const MAX_SEED_LEN: usize = 32;
 pub const MAX_SEEDS: usize = 16;
 #[cfg(feature = "decode")]
 /// Maximum string length of a base58 encoded address.
-const MAX_BASE58_LEN: usize = 44;
+pub const MAX_BASE58_LEN: usize = 44;
+
+#[cfg(feature = "decode")]
+/// Conventional number of characters kept on each side by
+/// [`Address::to_abbreviated`].
+pub const ABBREVIATION_LEN: usize = 4;
 
 /// Marker used to find program derived addresses (PDAs).
 #[cfg(target_arch = "bpf")]
@@ -98,6 +106,17 @@ pub struct Address(pub(crate) [u8; 32]);
 #[cfg(feature = "sanitize")]
 impl solana_sanitize::Sanitize for Address {}
 
+/// Zeroing an `Address` overwrites its bytes with zeros.
+///
+/// `ZeroizeOnDrop` is intentionally not implemented: `Address` may be `Copy`,
+/// which rules out a `Drop` impl. Wrap it in [`zeroize::Zeroizing`] instead.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Address {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 #[cfg(feature = "decode")]
 impl FromStr for Address {
     type Err = ParseAddressError;
@@ -169,6 +188,34 @@ impl TryFrom<&str> for Address {
     }
 }
 
+/// Return `true` if `seeds` satisfy the [`MAX_SEEDS`] and [`MAX_SEED_LEN`]
+/// limits enforced when creating a program derived address.
+///
+/// This checks the seeds exactly as passed to `create_program_address`. Seeds
+/// passed to `find_program_address` must also leave room for the bump seed,
+/// i.e. `validate_seeds` must hold with one more single-byte seed appended.
+///
+/// Being a `const fn`, it can be used to reject invalid static seeds at
+/// compile time:
+///
+/// ```
+/// # use solana_address::validate_seeds;
+/// const _: () = assert!(validate_seeds(&[b"vault", &[1, 2, 3]]));
+/// ```
+pub const fn validate_seeds(seeds: &[&[u8]]) -> bool {
+    if seeds.len() > MAX_SEEDS {
+        return false;
+    }
+    let mut i = 0;
+    while i < seeds.len() {
+        if seeds[i].len() > MAX_SEED_LEN {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 // If target_os = "solana" or target_arch = "bpf", then this panics so there
 // are no dependencies; otherwise this should be opt-in so users don't need the
 // curve25519 dependency.
@@ -193,6 +240,16 @@ impl Address {
         Self(address_array)
     }
 
+    #[cfg(feature = "decode")]
+    /// Parse an `Address` from a byte slice.
+    ///
+    /// This is equivalent to `TryFrom<&[u8]>`, but returns
+    /// [`ParseAddressError::WrongSize`] when `slice` is not exactly 32 bytes
+    /// long, so byte and string parsing share a single error type.
+    pub fn from_bytes(slice: &[u8]) -> Result<Self, ParseAddressError> {
+        Self::try_from(slice).map_err(|_| ParseAddressError::WrongSize)
+    }
+
     #[cfg(feature = "decode")]
     /// Decode a string into an `Address`, usable in a const context
     pub const fn from_str_const(s: &str) -> Self {
@@ -237,6 +294,21 @@ impl Address {
         Self::from(b)
     }
 
+    #[cfg(feature = "alloc")]
+    /// Create `count` distinct `Address`es for tests and benchmarks.
+    ///
+    /// Unlike [`Address::new_unique`], the result is deterministic: the same
+    /// `count` always yields the same addresses, in ascending order.
+    pub fn sequence(count: usize) -> Vec<Self> {
+        (1..=count as u64)
+            .map(|i| {
+                let mut b = [0u8; ADDRESS_BYTES];
+                b[0..8].copy_from_slice(&i.to_be_bytes());
+                Self::from(b)
+            })
+            .collect()
+    }
+
     // If target_os = "solana" or target_arch = "bpf", then the
     // `solana_sha256_hasher` crate will use syscalls which bring no
     // dependencies; otherwise, this should be opt-in so users don't
@@ -267,11 +339,112 @@ impl Address {
     }
 
     /// Return a reference to the `Address`'s byte array.
+    ///
+    /// `Address` deliberately does not implement `AsRef<[u8; 32]>`: a second
+    /// `AsRef` impl would make the common `key.as_ref()` in PDA seed lists
+    /// ambiguous. Use this method to get the fixed-size array instead.
     #[inline(always)]
     pub const fn as_array(&self) -> &[u8; 32] {
         &self.0
     }
 
+    #[cfg(feature = "decode")]
+    /// Encode the `Address` as base58 into a caller-provided buffer.
+    ///
+    /// Returns the number of bytes written; the encoded string is
+    /// `&out[..len]` and is always valid UTF-8. Unlike formatting through
+    /// [`Display`](core::fmt::Display) this never allocates, which makes it
+    /// suitable for hot paths that render many addresses.
+    pub fn write_base58(&self, out: &mut [u8; MAX_BASE58_LEN]) -> usize {
+        five8::encode_32(&self.0, out) as usize
+    }
+
+    #[cfg(feature = "decode")]
+    /// Return `true` if the base58 form of the `Address` starts with `prefix`.
+    ///
+    /// The address is encoded into a stack buffer, so this is cheap enough to
+    /// call once per candidate in a vanity grinding loop.
+    pub fn starts_with_base58(&self, prefix: &str) -> bool {
+        let mut out = [0u8; MAX_BASE58_LEN];
+        let len = self.write_base58(&mut out);
+        out[..len].starts_with(prefix.as_bytes())
+    }
+
+    #[cfg(all(feature = "decode", feature = "alloc"))]
+    /// Abbreviate the base58 form of the `Address` for display, keeping the
+    /// first `prefix` and last `suffix` characters joined by `…`.
+    ///
+    /// If the abbreviation would not be shorter than the full base58 string,
+    /// the full string is returned unchanged. [`ABBREVIATION_LEN`] is the
+    /// conventional width for both sides.
+    ///
+    /// ```
+    /// # use solana_address::{Address, ABBREVIATION_LEN};
+    /// let address: Address = "14grJpemFaf88c8tiVb77W7TYg2W3ir6pfkKz3YjhhZ5".parse().unwrap();
+    /// assert_eq!(
+    ///     address.to_abbreviated(ABBREVIATION_LEN, ABBREVIATION_LEN),
+    ///     "14gr…hhZ5"
+    /// );
+    /// ```
+    pub fn to_abbreviated(&self, prefix: usize, suffix: usize) -> alloc::string::String {
+        let mut out = [0u8; MAX_BASE58_LEN];
+        let len = self.write_base58(&mut out);
+        // any sequence of base58 chars is valid utf8
+        let encoded = unsafe { core::str::from_utf8_unchecked(&out[..len]) };
+        if prefix.saturating_add(suffix) >= len {
+            return encoded.into();
+        }
+        let mut abbreviated =
+            alloc::string::String::with_capacity(prefix + suffix + '…'.len_utf8());
+        abbreviated.push_str(&encoded[..prefix]);
+        abbreviated.push('…');
+        abbreviated.push_str(&encoded[len - suffix..]);
+        abbreviated
+    }
+
+    #[cfg(all(feature = "decode", feature = "alloc"))]
+    /// Encode the `Address` as a 64-character lowercase hex string.
+    ///
+    /// This is an alternate encoding for interop with tooling that does not
+    /// speak base58; [`Display`](core::fmt::Display) remains base58.
+    pub fn to_hex(&self) -> alloc::string::String {
+        const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+        let mut out = alloc::string::String::with_capacity(ADDRESS_BYTES * 2);
+        for byte in self.0.iter() {
+            out.push(HEX_CHARS[(byte >> 4) as usize] as char);
+            out.push(HEX_CHARS[(byte & 0x0f) as usize] as char);
+        }
+        out
+    }
+
+    #[cfg(feature = "decode")]
+    /// Decode an `Address` from a 64-character hex string.
+    ///
+    /// An optional `0x` prefix is accepted, and digits may be upper or lower
+    /// case. Returns [`ParseAddressError::WrongSize`] if the input does not
+    /// contain exactly 64 hex digits and [`ParseAddressError::Invalid`] if it
+    /// contains a non-hex character.
+    pub fn from_hex(s: &str) -> Result<Self, ParseAddressError> {
+        fn nibble(c: u8) -> Result<u8, ParseAddressError> {
+            match c {
+                b'0'..=b'9' => Ok(c - b'0'),
+                b'a'..=b'f' => Ok(c - b'a' + 10),
+                b'A'..=b'F' => Ok(c - b'A' + 10),
+                _ => Err(ParseAddressError::Invalid),
+            }
+        }
+
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        if s.len() != ADDRESS_BYTES * 2 {
+            return Err(ParseAddressError::WrongSize);
+        }
+        let mut bytes = [0; ADDRESS_BYTES];
+        for (byte, pair) in bytes.iter_mut().zip(s.as_bytes().chunks_exact(2)) {
+            *byte = (nibble(pair[0])? << 4) | nibble(pair[1])?;
+        }
+        Ok(Address(bytes))
+    }
+
     // If target_os = "solana" or target_arch = "bpf", then this panics so there
     // are no dependencies; otherwise, this should be opt-in so users don't need
     // the curve25519 dependency.
@@ -280,6 +453,32 @@ impl Address {
         bytes_are_curve_point(self)
     }
 
+    /// Return a hint whether this `Address` is a program derived address.
+    ///
+    /// Returns `Some(true)` if the address lies off the ed25519 curve, as all
+    /// program derived addresses do, and `Some(false)` if it lies on the curve.
+    /// Returns `None` when the `curve25519` feature is not enabled, or when
+    /// running on-chain, since the check cannot be performed.
+    ///
+    /// An on-curve address is a valid ed25519 public key, but that does not
+    /// guarantee that anyone knows the corresponding private key.
+    pub fn is_likely_pda(&self) -> Option<bool> {
+        #[cfg(all(
+            feature = "curve25519",
+            not(any(target_os = "solana", target_arch = "bpf"))
+        ))]
+        {
+            Some(!self.is_on_curve())
+        }
+        #[cfg(not(all(
+            feature = "curve25519",
+            not(any(target_os = "solana", target_arch = "bpf"))
+        )))]
+        {
+            None
+        }
+    }
+
     /// Log an `Address` value.
     #[cfg(all(not(any(target_os = "solana", target_arch = "bpf")), feature = "std"))]
     pub fn log(&self) {
@@ -299,10 +498,45 @@ impl AsMut<[u8]> for Address {
     }
 }
 
+/// Byte-wise XOR of two addresses, e.g. to compute XOR distances for
+/// DHT-style routing or sharding over addresses.
+impl BitXorAssign<&Address> for Address {
+    fn bitxor_assign(&mut self, rhs: &Address) {
+        for (byte, rhs) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *byte ^= rhs;
+        }
+    }
+}
+
+impl BitXorAssign for Address {
+    fn bitxor_assign(&mut self, rhs: Address) {
+        *self ^= &rhs;
+    }
+}
+
+impl BitXor<&Address> for &Address {
+    type Output = Address;
+
+    fn bitxor(self, rhs: &Address) -> Address {
+        let mut result = Address::from(self);
+        result ^= rhs;
+        result
+    }
+}
+
+impl BitXor for Address {
+    type Output = Address;
+
+    fn bitxor(mut self, rhs: Address) -> Address {
+        self ^= &rhs;
+        self
+    }
+}
+
 #[cfg(feature = "decode")]
 fn write_as_base58(f: &mut core::fmt::Formatter, p: &Address) -> core::fmt::Result {
     let mut out = [0u8; MAX_BASE58_LEN];
-    let len = five8::encode_32(&p.0, &mut out) as usize;
+    let len = p.write_base58(&mut out);
     // any sequence of base58 chars is valid utf8
     let as_str = unsafe { core::str::from_utf8_unchecked(&out[..len]) };
     f.write_str(as_str)
@@ -480,6 +714,16 @@ mod tests {
         assert!(Address::new_unique() != Address::new_unique());
     }
 
+    #[test]
+    fn test_sequence() {
+        let addresses = Address::sequence(100);
+        assert_eq!(addresses.len(), 100);
+        assert!(addresses.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(addresses, Address::sequence(100));
+        assert_eq!(addresses[..10], Address::sequence(10)[..]);
+        assert!(Address::sequence(0).is_empty());
+    }
+
     #[test]
     fn address_fromstr() {
         let address = Address::new_unique();
@@ -667,6 +911,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_seeds() {
+        const _: () = assert!(validate_seeds(&[b"vault", &[0; MAX_SEED_LEN]]));
+        assert!(validate_seeds(&[]));
+        assert!(validate_seeds(&[&[][..]; MAX_SEEDS]));
+        assert!(!validate_seeds(&[&[][..]; MAX_SEEDS + 1]));
+        assert!(!validate_seeds(&[b"vault", &[0; MAX_SEED_LEN + 1]]));
+    }
+
     #[test]
     fn test_address_off_curve() {
         // try a bunch of random input, all successful generated program
@@ -700,6 +953,150 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_try_find_program_address() {
+        let program_id = Address::new_unique();
+        assert_eq!(
+            Address::try_find_program_address(&[b"Lil'", b"Bits"], &program_id),
+            Some(Address::find_program_address(
+                &[b"Lil'", b"Bits"],
+                &program_id
+            ))
+        );
+
+        // invalid seed sets exhaust the search without panicking
+        let exceeded_seed = &[127; MAX_SEED_LEN + 1];
+        assert_eq!(
+            Address::try_find_program_address(&[exceeded_seed], &program_id),
+            None
+        );
+        let max_seeds: &[&[u8]] = &[&[0u8][..]; MAX_SEEDS];
+        assert_eq!(
+            Address::try_find_program_address(max_seeds, &program_id),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_program_address_with_tries() {
+        let mut total_tries = 0u32;
+        for _ in 0..1_000 {
+            let program_id = Address::new_unique();
+            let (address, bump_seed, tries) =
+                Address::find_program_address_with_tries(&[b"Lil'", b"Bits"], &program_id);
+            assert_eq!(
+                (Address::from(&address), bump_seed),
+                Address::find_program_address(&[b"Lil'", b"Bits"], &program_id)
+            );
+            assert!(tries >= 1);
+            // every rejected candidate landed on the curve
+            for rejected in (0..tries - 1).map(|i| u8::MAX - i) {
+                assert_eq!(
+                    Address::create_program_address(&[b"Lil'", b"Bits", &[rejected]], &program_id),
+                    Err(AddressError::InvalidSeeds)
+                );
+            }
+            assert_eq!(u8::MAX - bump_seed, tries - 1);
+            total_tries += tries as u32;
+        }
+        // roughly half of all candidates land on the curve, so the search
+        // takes about two tries on average
+        assert!((1_500..2_500).contains(&total_tries));
+    }
+
+    #[test]
+    fn test_find_program_addresses() {
+        let program_id = Address::new_unique();
+        let mints = Address::sequence(3);
+        let seed_sets = mints
+            .iter()
+            .map(|mint| [b"vault".as_ref(), mint.as_ref()])
+            .collect::<Vec<_>>();
+        let seed_sets = seed_sets.iter().map(|seeds| &seeds[..]).collect::<Vec<_>>();
+
+        let addresses = Address::find_program_addresses(&seed_sets, &program_id);
+        assert_eq!(addresses.len(), mints.len());
+        for (seeds, found) in seed_sets.iter().zip(addresses.iter()) {
+            assert_eq!(found, &Address::find_program_address(seeds, &program_id));
+        }
+        assert_ne!(addresses[0].0, addresses[1].0);
+        assert!(Address::find_program_addresses(&[], &program_id).is_empty());
+    }
+
+    #[test]
+    fn test_program_addresses_by_bump() {
+        let program_id = Address::new_unique();
+        let addresses = Address::program_addresses_by_bump(&[b"Lil'", b"Bits"], &program_id)
+            .collect::<Vec<_>>();
+        assert!(!addresses.is_empty());
+        assert_eq!(
+            addresses
+                .first()
+                .map(|(bump_seed, address)| (Address::from(address), *bump_seed)),
+            Some(Address::find_program_address(
+                &[b"Lil'", b"Bits"],
+                &program_id
+            ))
+        );
+        assert!(addresses.windows(2).all(|pair| pair[0].0 > pair[1].0));
+        for (bump_seed, address) in addresses.iter() {
+            assert_eq!(
+                Address::create_program_address(&[b"Lil'", b"Bits", &[*bump_seed]], &program_id),
+                Ok(Address::from(address))
+            );
+        }
+        // every bump seed is accounted for, either yielded or on the curve
+        for bump_seed in 0..=u8::MAX {
+            if !addresses.iter().any(|(bump, _)| *bump == bump_seed) {
+                assert_eq!(
+                    Address::create_program_address(&[b"Lil'", b"Bits", &[bump_seed]], &program_id),
+                    Err(AddressError::InvalidSeeds)
+                );
+            }
+        }
+
+        let exceeded_seed = &[127; MAX_SEED_LEN + 1];
+        assert_eq!(
+            Address::program_addresses_by_bump(&[exceeded_seed], &program_id).count(),
+            0
+        );
+        let max_seeds: &[&[u8]] = &[&[0u8][..]; MAX_SEEDS];
+        assert_eq!(
+            Address::program_addresses_by_bump(max_seeds, &program_id).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_find_program_address_with_seeds_out() {
+        let program_id = Address::new_unique();
+        let (address, bump_seed, seeds) =
+            Address::find_program_address_with_seeds_out(&[b"Lil'", b"Bits"], &program_id);
+        let (expected_address, expected_bump_seed) =
+            Address::find_program_address(&[b"Lil'", b"Bits"], &program_id);
+        assert_eq!(address, expected_address);
+        assert_eq!(bump_seed, expected_bump_seed);
+        assert_eq!(
+            seeds,
+            std::vec![b"Lil'".to_vec(), b"Bits".to_vec(), std::vec![bump_seed]]
+        );
+        let seeds = seeds.iter().map(|seed| seed.as_slice()).collect::<Vec<_>>();
+        assert_eq!(
+            Address::create_program_address(&seeds, &program_id).unwrap(),
+            address
+        );
+    }
+
+    #[test]
+    fn test_is_likely_pda() {
+        let (pda, _) = Address::find_program_address(&[b"Lil'", b"Bits"], &Address::new_unique());
+        assert_eq!(pda.is_likely_pda(), Some(true));
+
+        let keypair_pubkey =
+            Address::from(curve25519_dalek::constants::ED25519_BASEPOINT_COMPRESSED.to_bytes());
+        assert_eq!(keypair_pubkey.is_likely_pda(), Some(false));
+    }
+
     fn address_from_seed_by_marker(marker: &[u8]) -> Result<Address, AddressError> {
         let key = Address::new_unique();
         let owner = Address::default();
@@ -732,6 +1129,147 @@ mod tests {
         assert_eq!(key.as_array().as_ptr(), key.0.as_ptr());
     }
 
+    #[test]
+    fn test_write_base58() {
+        for key in [
+            Address::default(),
+            Address::new_unique(),
+            Address::from([u8::MAX; 32]),
+        ] {
+            let mut out = [0u8; MAX_BASE58_LEN];
+            let len = key.write_base58(&mut out);
+            let encoded = from_utf8(&out[..len]).unwrap();
+            assert_eq!(encoded, key.to_string());
+            assert_eq!(encoded, encode_address(key.as_array()));
+            assert_eq!(Address::from_str(encoded), Ok(key));
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut key = Address::from([7u8; 32]);
+        key.zeroize();
+        assert_eq!(key, Address::default());
+
+        let key = zeroize::Zeroizing::new(Address::from([7u8; 32]));
+        assert_eq!(key.as_array(), &[7u8; 32]);
+    }
+
+    #[test]
+    fn test_starts_with_base58() {
+        let key = Address::from_str("14grJpemFaf88c8tiVb77W7TYg2W3ir6pfkKz3YjhhZ5").unwrap();
+        assert!(key.starts_with_base58(""));
+        assert!(key.starts_with_base58("14gr"));
+        assert!(key.starts_with_base58("14grJpemFaf88c8tiVb77W7TYg2W3ir6pfkKz3YjhhZ5"));
+        assert!(!key.starts_with_base58("14gR"));
+        assert!(!key.starts_with_base58("4gr"));
+        assert!(!key.starts_with_base58("14grJpemFaf88c8tiVb77W7TYg2W3ir6pfkKz3YjhhZ51"));
+
+        for _ in 0..100 {
+            let key = Address::new_unique();
+            let encoded = key.to_string();
+            assert!(key.starts_with_base58(&encoded[..5]));
+        }
+    }
+
+    #[test]
+    fn test_to_abbreviated() {
+        let key = Address::from_str("14grJpemFaf88c8tiVb77W7TYg2W3ir6pfkKz3YjhhZ5").unwrap();
+        assert_eq!(
+            key.to_abbreviated(ABBREVIATION_LEN, ABBREVIATION_LEN),
+            "14gr…hhZ5"
+        );
+        assert_eq!(key.to_abbreviated(2, 0), "14…");
+        assert_eq!(key.to_abbreviated(0, 3), "…hZ5");
+        assert_eq!(key.to_abbreviated(22, 22), key.to_string());
+        assert_eq!(key.to_abbreviated(usize::MAX, 1), key.to_string());
+
+        // the all-zero address encodes to 32 characters
+        let key = Address::default();
+        assert_eq!(key.to_abbreviated(16, 16), key.to_string());
+        assert_eq!(
+            key.to_abbreviated(16, 15),
+            std::format!("{}…{}", "1".repeat(16), "1".repeat(15))
+        );
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let bytes = [3u8; 64];
+        assert_eq!(
+            Address::from_bytes(&bytes[..32]),
+            Ok(Address::from([3u8; 32]))
+        );
+        for len in [0, 31, 33, 64] {
+            assert_eq!(
+                Address::from_bytes(&bytes[..len]),
+                Err(ParseAddressError::WrongSize)
+            );
+        }
+    }
+
+    #[test]
+    fn test_hex() {
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i as u8).wrapping_mul(37);
+        }
+        let key = Address::from(bytes);
+        let hex = key.to_hex();
+        assert_eq!(hex.len(), 64);
+        assert!(hex.starts_with("00254a6f94b9de03"));
+        assert_eq!(Address::from_hex(&hex), Ok(Address::from(&key)));
+        assert_eq!(
+            Address::from_hex(&std::format!("0x{hex}")),
+            Ok(Address::from(&key))
+        );
+        assert_eq!(Address::from_hex(&hex.to_uppercase()), Ok(key));
+
+        assert_eq!(
+            Address::from_hex(&hex[..62]),
+            Err(ParseAddressError::WrongSize)
+        );
+        assert_eq!(
+            Address::from_hex(&std::format!("{hex}00")),
+            Err(ParseAddressError::WrongSize)
+        );
+        assert_eq!(
+            Address::from_hex(&std::format!("0X{hex}")),
+            Err(ParseAddressError::WrongSize)
+        );
+        assert_eq!(
+            Address::from_hex(&std::format!("zz{}", &hex[2..])),
+            Err(ParseAddressError::Invalid)
+        );
+        // base58 is not accepted
+        assert!(Address::from_hex(&key.to_string()).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_bitxor() {
+        let a = Address::new_unique();
+        let b = Address::new_unique();
+        let expected = Address::from(array::from_fn::<u8, 32, _>(|i| {
+            a.as_array()[i] ^ b.as_array()[i]
+        }));
+
+        assert_eq!(&a ^ &b, expected);
+        assert_eq!(&b ^ &a, expected);
+        assert_eq!(Address::from(&a) ^ Address::from(&b), expected);
+        assert_eq!(&a ^ &a, Address::default());
+        assert_eq!(&a ^ &Address::default(), a);
+
+        let mut c = Address::from(&a);
+        c ^= &b;
+        assert_eq!(c, expected);
+        c ^= Address::from(&b);
+        assert_eq!(c, a);
+    }
+
     #[test]
     fn test_address_macro() {
         const ADDRESS: Address =
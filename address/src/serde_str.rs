@@ -0,0 +1,88 @@
+//! (De)serialize an [`Address`] as a base58 string.
+//!
+//! The `Serialize` and `Deserialize` impls derived for `Address` use the raw
+//! 32-byte array, which is compact but unreadable in formats like JSON or
+//! TOML. Use this module with `#[serde(with = "...")]` on individual fields to
+//! store them in their base58 string form instead.
+//!
+//! ```
+//! # use {serde_derive::{Deserialize, Serialize}, solana_address::Address};
+//! #[derive(Deserialize, Serialize)]
+//! struct Config {
+//!     #[serde(with = "solana_address::serde_str")]
+//!     authority: Address,
+//! }
+//! ```
+use {
+    crate::Address,
+    core::{fmt, str::FromStr},
+    serde::{de::Visitor, Deserializer, Serializer},
+};
+
+struct AddressStrVisitor;
+
+impl Visitor<'_> for AddressStrVisitor {
+    type Value = Address;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a base58 encoded address")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Address::from_str(value).map_err(E::custom)
+    }
+}
+
+pub fn serialize<S>(address: &Address, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(address)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Address, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(AddressStrVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde_derive::{Deserialize, Serialize},
+    };
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Config {
+        #[serde(with = "crate::serde_str")]
+        authority: Address,
+    }
+
+    #[test]
+    fn test_serde_str_json() {
+        let config = Config {
+            authority: Address::from_str("BPFLoaderUpgradeab1e11111111111111111111111").unwrap(),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            json,
+            r#"{"authority":"BPFLoaderUpgradeab1e11111111111111111111111"}"#
+        );
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn test_serde_str_rejects_invalid() {
+        assert!(serde_json::from_str::<Config>(r#"{"authority":"0OIl"}"#).is_err());
+        assert!(serde_json::from_str::<Config>(r#"{"authority":"1111"}"#).is_err());
+        let bytes = serde_json::to_string(&[0u8; 32]).unwrap();
+        assert!(
+            serde_json::from_str::<Config>(&std::format!(r#"{{"authority":{bytes}}}"#)).is_err()
+        );
+    }
+}
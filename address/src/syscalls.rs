@@ -341,6 +341,153 @@ impl Address {
         }
     }
 
+    /// Find a valid [program derived address][pda] and return it together with
+    /// its bump seed and the full list of seeds used to derive it.
+    ///
+    /// [pda]: https://solana.com/docs/core/cpi#program-derived-addresses
+    ///
+    /// The returned seed list is `seeds` followed by a single-byte seed
+    /// containing the bump, i.e. exactly the seeds that must be passed to
+    /// [`create_program_address`] or `invoke_signed` to sign for the address.
+    /// This allows callers to store the signer seeds without re-deriving the
+    /// address or reconstructing the seed list.
+    ///
+    /// See the documentation for [`find_program_address`] for a full description.
+    ///
+    /// [`create_program_address`]: Address::create_program_address
+    /// [`find_program_address`]: Address::find_program_address
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`find_program_address`].
+    #[cfg(all(
+        feature = "alloc",
+        any(target_os = "solana", target_arch = "bpf", feature = "curve25519")
+    ))]
+    pub fn find_program_address_with_seeds_out(
+        seeds: &[&[u8]],
+        program_id: &Address,
+    ) -> (Address, u8, alloc::vec::Vec<alloc::vec::Vec<u8>>) {
+        let (address, bump_seed) = Self::find_program_address(seeds, program_id);
+        let mut seeds_with_bump = alloc::vec::Vec::with_capacity(seeds.len().saturating_add(1));
+        seeds_with_bump.extend(seeds.iter().map(|seed| seed.to_vec()));
+        seeds_with_bump.push(alloc::vec![bump_seed]);
+        (address, bump_seed, seeds_with_bump)
+    }
+
+    /// Find a valid [program derived address][pda] and its bump seed, along
+    /// with the number of bump seed candidates that were tested.
+    ///
+    /// [pda]: https://solana.com/docs/core/cpi#program-derived-addresses
+    ///
+    /// The search starts at `u8::MAX` and counts down, so `tries` is
+    /// `u8::MAX - bump_seed + 1`, and `tries - 1` candidates landed on the
+    /// ed25519 curve before a valid address was found. This is useful for
+    /// diagnosing seed sets that need unusually long searches.
+    ///
+    /// See the documentation for [`find_program_address`] for a full description.
+    ///
+    /// [`find_program_address`]: Address::find_program_address
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`find_program_address`].
+    #[cfg(any(target_os = "solana", target_arch = "bpf", feature = "curve25519"))]
+    pub fn find_program_address_with_tries(
+        seeds: &[&[u8]],
+        program_id: &Address,
+    ) -> (Address, u8, u8) {
+        let (address, bump_seed) = Self::find_program_address(seeds, program_id);
+        (address, bump_seed, u8::MAX - bump_seed + 1)
+    }
+
+    /// Find a valid [program derived address][pda] and bump seed for each of
+    /// `seed_sets`, all derived against the same `program_id`.
+    ///
+    /// [pda]: https://solana.com/docs/core/cpi#program-derived-addresses
+    ///
+    /// The result is in the same order as `seed_sets`, and each entry is
+    /// exactly what [`find_program_address`] returns for that seed set.
+    ///
+    /// [`find_program_address`]: Address::find_program_address
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`find_program_address`], for any
+    /// of the seed sets.
+    #[cfg(all(
+        feature = "alloc",
+        any(target_os = "solana", target_arch = "bpf", feature = "curve25519")
+    ))]
+    pub fn find_program_addresses(
+        seed_sets: &[&[&[u8]]],
+        program_id: &Address,
+    ) -> alloc::vec::Vec<(Address, u8)> {
+        seed_sets
+            .iter()
+            .map(|seeds| Self::find_program_address(seeds, program_id))
+            .collect()
+    }
+
+    /// Iterate over every bump seed, from `u8::MAX` down to `0`, that derives
+    /// a valid [program derived address][pda] for `seeds` and `program_id`.
+    ///
+    /// [pda]: https://solana.com/docs/core/cpi#program-derived-addresses
+    ///
+    /// Each item is a `(bump_seed, address)` pair where `address` is the result
+    /// of [`create_program_address`] with `bump_seed` appended to `seeds`. Bump
+    /// seeds whose hash lands on the ed25519 curve are skipped.
+    ///
+    /// Unlike [`find_program_address`], which only searches bumps `u8::MAX`
+    /// down to `1`, this also tries bump `0`. The first item is the canonical
+    /// bump returned by [`find_program_address`] only if its bump is non-zero;
+    /// when bump `0` is the only valid one, this yields `(0, address)` while
+    /// [`find_program_address`] finds no address.
+    ///
+    /// If `seeds` violates the seed limits of [`create_program_address`] the
+    /// iterator yields nothing.
+    ///
+    /// [`create_program_address`]: Address::create_program_address
+    /// [`find_program_address`]: Address::find_program_address
+    #[cfg(all(
+        feature = "alloc",
+        any(target_os = "solana", target_arch = "bpf", feature = "curve25519")
+    ))]
+    pub fn program_addresses_by_bump<'a>(
+        seeds: &'a [&'a [u8]],
+        program_id: &'a Address,
+    ) -> impl Iterator<Item = (u8, Address)> + 'a {
+        // every possible bump seed as a one-byte slice, so the bump slot of
+        // `seeds_with_bump` can be overwritten without reallocating
+        static BUMP_SEEDS: [u8; 256] = {
+            let mut bump_seeds = [0u8; 256];
+            let mut i = 0;
+            while i < bump_seeds.len() {
+                bump_seeds[i] = i as u8;
+                i += 1;
+            }
+            bump_seeds
+        };
+
+        let mut seeds_with_bump = alloc::vec::Vec::with_capacity(seeds.len() + 1);
+        seeds_with_bump.extend_from_slice(seeds);
+        seeds_with_bump.push(&BUMP_SEEDS[..0]);
+        let bump_index = seeds.len();
+
+        (0..=u8::MAX)
+            .rev()
+            .map_while(move |bump_seed| {
+                seeds_with_bump[bump_index] =
+                    core::slice::from_ref(&BUMP_SEEDS[usize::from(bump_seed)]);
+                match Self::create_program_address(&seeds_with_bump, program_id) {
+                    Ok(address) => Some(Some((bump_seed, address))),
+                    Err(AddressError::InvalidSeeds) => Some(None),
+                    _ => None,
+                }
+            })
+            .flatten()
+    }
+
     /// Create a valid [program derived address][pda] without searching for a bump seed.
     ///
     /// [pda]: https://solana.com/docs/core/cpi#program-derived-addresses
@@ -392,12 +539,7 @@ impl Address {
         seeds: &[&[u8]],
         program_id: &Address,
     ) -> Result<Address, AddressError> {
-        use crate::{MAX_SEEDS, MAX_SEED_LEN};
-
-        if seeds.len() > MAX_SEEDS {
-            return Err(AddressError::MaxSeedLengthExceeded);
-        }
-        if seeds.iter().any(|seed| seed.len() > MAX_SEED_LEN) {
+        if !crate::validate_seeds(seeds) {
             return Err(AddressError::MaxSeedLengthExceeded);
         }
 
@@ -2,6 +2,7 @@ use {
     criterion::{criterion_group, criterion_main, Criterion},
     solana_bls_signatures::{
         keypair::Keypair,
+        proof_of_possession::{verify_proofs_of_possession, ProofOfPossession},
         pubkey::{Pubkey, PubkeyProjective, VerifiablePubkey},
         signature::{Signature, SignatureProjective},
     },
@@ -25,6 +26,36 @@ fn bench_single_signature(c: &mut Criterion) {
     group.finish();
 }
 
+// Benchmark for verifying many messages signed by the same key
+fn bench_prepared_pubkey(c: &mut Criterion) {
+    let mut group = c.benchmark_group("prepared_pubkey");
+    let keypair = Keypair::new();
+    let pubkey_projective: PubkeyProjective = (&keypair.public).try_into().unwrap();
+    let messages: Vec<Vec<u8>> = (0..64u32).map(|i| i.to_le_bytes().to_vec()).collect();
+    let signatures: Vec<Signature> = messages
+        .iter()
+        .map(|message| keypair.sign(message).into())
+        .collect();
+
+    group.bench_function("verify_64_unprepared", |b| {
+        b.iter(|| {
+            for (message, signature) in messages.iter().zip(&signatures) {
+                black_box(pubkey_projective.verify_signature(signature, message)).unwrap();
+            }
+        });
+    });
+
+    group.bench_function("verify_64_prepared", |b| {
+        b.iter(|| {
+            let prepared = pubkey_projective.prepare();
+            for (message, signature) in messages.iter().zip(&signatures) {
+                black_box(prepared.verify(signature, message)).unwrap();
+            }
+        });
+    });
+    group.finish();
+}
+
 // Worst-case benchmark for aggregate signature verification
 fn bench_aggregate(c: &mut Criterion) {
     let mut group = c.benchmark_group("aggregate_verify");
@@ -123,6 +154,34 @@ fn bench_proof_of_possession(c: &mut Criterion) {
             black_box(keypair.public.verify_proof_of_possession(&pop)).unwrap();
         })
     });
+
+    let mut group = c.benchmark_group("proof_of_possession_batch_verify");
+    for num_validators in [64, 128, 256, 512, 1024, 2048].iter() {
+        let keypairs: Vec<Keypair> = (0..*num_validators).map(|_| Keypair::new()).collect();
+        let proofs: Vec<ProofOfPossession> = keypairs
+            .iter()
+            .map(|kp| kp.proof_of_possession().into())
+            .collect();
+        let entries: Vec<(&Pubkey, &ProofOfPossession)> = keypairs
+            .iter()
+            .map(|kp| &kp.public)
+            .zip(proofs.iter())
+            .collect();
+
+        group.bench_function(format!("{num_validators} sequential verification"), |b| {
+            b.iter(|| {
+                for (pubkey, proof) in &entries {
+                    assert!(black_box(pubkey.verify_proof_of_possession(*proof)).unwrap());
+                }
+            });
+        });
+        group.bench_function(format!("{num_validators} batch verification"), |b| {
+            b.iter(|| {
+                assert!(black_box(verify_proofs_of_possession(&entries)).unwrap());
+            });
+        });
+    }
+    group.finish()
 }
 
 // Benchmark for batch verification functions
@@ -162,6 +221,23 @@ fn bench_batch_verification(c: &mut Criterion) {
             },
         );
 
+        let items: Vec<(&Pubkey, &[u8], &Signature)> = pubkeys
+            .iter()
+            .zip(messages.iter())
+            .zip(signatures.iter())
+            .map(|((pubkey, message), signature)| (pubkey, message.as_slice(), signature))
+            .collect();
+        group.bench_function(
+            format!("{num_validators} random-scalar batch verification"),
+            |b| {
+                b.iter(|| {
+                    let verification_result =
+                        black_box(SignatureProjective::batch_verify(&items).unwrap());
+                    assert!(verification_result);
+                });
+            },
+        );
+
         #[cfg(feature = "parallel")]
         group.bench_function(
             format!("{num_validators} parallel batch verification"),
@@ -186,6 +262,7 @@ fn bench_batch_verification(c: &mut Criterion) {
 criterion_group!(
     benches,
     bench_single_signature,
+    bench_prepared_pubkey,
     bench_aggregate,
     bench_key_generation,
     bench_proof_of_possession,
@@ -9,7 +9,7 @@ pub enum BlsError {
     #[error("Key derivation failed")]
     KeyDerivation,
     #[error("Point representation conversion failed")]
-    PointConversion, // TODO: could be more specific here
+    PointConversion,
     #[error("Failed to parse from string")]
     ParseFromString, // TODO: update after more precise error handling
     #[error("Failed to parse from bytes")]
@@ -18,6 +18,25 @@ pub enum BlsError {
     InputLengthMismatch,
 }
 
+/// The reason serialized bytes are not a valid BLS curve point
+///
+/// Conversions and verification report malformed points as
+/// [`BlsError::PointConversion`]; the `validate_point` methods of the
+/// serialized point types return this finer-grained reason instead.
+#[derive(Error, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PointError {
+    #[error("Bytes do not encode a valid curve point")]
+    Decompression,
+    #[error("Point is not in the prime-order subgroup")]
+    NotInSubgroup,
+}
+
+impl From<PointError> for BlsError {
+    fn from(_: PointError) -> Self {
+        BlsError::PointConversion
+    }
+}
+
 impl From<Infallible> for BlsError {
     fn from(_: Infallible) -> Self {
         unreachable!()
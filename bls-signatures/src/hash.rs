@@ -11,7 +11,12 @@ pub const HASH_TO_POINT_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NU
 
 /// Hash a message to a G2 point
 pub fn hash_message_to_point(message: &[u8]) -> G2Projective {
-    G2Projective::hash_to_curve(message, HASH_TO_POINT_DST, &[])
+    hash_message_to_point_with_dst(message, HASH_TO_POINT_DST)
+}
+
+/// Hash a message to a G2 point under a custom domain separation tag
+pub fn hash_message_to_point_with_dst(message: &[u8], dst: &[u8]) -> G2Projective {
+    G2Projective::hash_to_curve(message, dst, &[])
 }
 
 /// Hash a pubkey to a G2 point
@@ -1,10 +1,3 @@
-use crate::{
-    error::BlsError,
-    proof_of_possession::ProofOfPossessionProjective,
-    pubkey::{Pubkey, PubkeyProjective, VerifiablePubkey, BLS_PUBLIC_KEY_AFFINE_SIZE},
-    secret_key::{SecretKey, BLS_SECRET_KEY_SIZE},
-    signature::{AsSignature, SignatureProjective},
-};
 #[cfg(feature = "solana-signer-derive")]
 use solana_signer::Signer;
 #[cfg(feature = "std")]
@@ -17,6 +10,16 @@ use std::{
     string::String,
     vec::Vec,
 };
+use {
+    crate::{
+        error::BlsError,
+        proof_of_possession::ProofOfPossessionProjective,
+        pubkey::{Pubkey, PubkeyProjective, VerifiablePubkey, BLS_PUBLIC_KEY_AFFINE_SIZE},
+        secret_key::{SecretKey, BLS_SECRET_KEY_SIZE},
+        signature::{AsSignature, SignatureProjective},
+    },
+    ff::Field,
+};
 
 /// Size of BLS keypair in bytes
 pub const BLS_KEYPAIR_SIZE: usize = BLS_SECRET_KEY_SIZE + BLS_PUBLIC_KEY_AFFINE_SIZE;
@@ -31,6 +34,7 @@ pub struct Keypair {
 impl Keypair {
     /// Constructs a new, random `Keypair` using `OsRng`
     #[allow(clippy::new_without_default)]
+    #[cfg(feature = "rand")]
     pub fn new() -> Self {
         let secret = SecretKey::new();
         let public = PubkeyProjective::from_secret(&secret).into();
@@ -44,6 +48,16 @@ impl Keypair {
         Ok(Self { secret, public })
     }
 
+    /// Deterministically derive a `Keypair` from a seed, so that the same seed
+    /// always yields the same keypair
+    ///
+    /// See [`SecretKey::from_seed`].
+    pub fn from_seed(seed: &[u8]) -> Result<Self, BlsError> {
+        let secret = SecretKey::from_seed(seed)?;
+        let public = PubkeyProjective::from_secret(&secret).into();
+        Ok(Self { secret, public })
+    }
+
     /// Derive a `BlsSecretKey` from a Solana signer
     #[cfg(feature = "solana-signer-derive")]
     pub fn derive_from_signer(signer: &dyn Signer, public_seed: &[u8]) -> Result<Self, BlsError> {
@@ -52,6 +66,30 @@ impl Keypair {
         Ok(Self { secret, public })
     }
 
+    /// Return the secret key bytes of the keypair
+    ///
+    /// The public key is not included since [`Keypair::from_bytes`]
+    /// recomputes it from the secret key. Use the `[u8; BLS_KEYPAIR_SIZE]`
+    /// conversion to serialize both halves.
+    pub fn to_bytes(&self) -> [u8; BLS_SECRET_KEY_SIZE] {
+        (&self.secret).into()
+    }
+
+    /// Reconstruct a `Keypair` from the secret key bytes returned by
+    /// [`Keypair::to_bytes`], recomputing the public key
+    ///
+    /// Returns [`BlsError::FieldDecode`] if the bytes do not encode a non-zero
+    /// scalar in range. A zero scalar would give the identity public key,
+    /// which key generation never produces.
+    pub fn from_bytes(bytes: &[u8; BLS_SECRET_KEY_SIZE]) -> Result<Self, BlsError> {
+        let secret = SecretKey::try_from(bytes.as_slice())?;
+        if bool::from(secret.0.is_zero()) {
+            return Err(BlsError::FieldDecode);
+        }
+        let public = PubkeyProjective::from_secret(&secret).into();
+        Ok(Self { secret, public })
+    }
+
     /// Generate a proof of possession for the given keypair
     pub fn proof_of_possession(&self) -> ProofOfPossessionProjective {
         self.secret.proof_of_possession()
@@ -68,6 +106,10 @@ impl Keypair {
     }
 }
 
+// the secret key is zeroized when the keypair is dropped
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Keypair {}
+
 impl TryFrom<&[u8]> for Keypair {
     type Error = BlsError;
     fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
@@ -145,9 +187,9 @@ impl Keypair {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "rand"))]
 mod tests {
-    use {super::*, tempfile::NamedTempFile};
+    use {super::*, crate::secret_key::BLS_SEED_MIN_SIZE, tempfile::NamedTempFile};
 
     #[test]
     fn test_keygen_derive() {
@@ -159,6 +201,59 @@ mod tests {
         assert_eq!(keypair.public, public);
     }
 
+    #[test]
+    fn test_keypair_bytes() {
+        let keypair = Keypair::new();
+        let bytes = keypair.to_bytes();
+        assert_eq!(bytes, <[u8; BLS_SECRET_KEY_SIZE]>::from(&keypair.secret));
+        assert_eq!(Keypair::from_bytes(&bytes), Ok(keypair));
+
+        // scalars at or above the group order are rejected
+        assert_eq!(
+            Keypair::from_bytes(&[0xff; BLS_SECRET_KEY_SIZE]),
+            Err(BlsError::FieldDecode)
+        );
+        // so is the zero scalar
+        assert_eq!(
+            Keypair::from_bytes(&[0u8; BLS_SECRET_KEY_SIZE]),
+            Err(BlsError::FieldDecode)
+        );
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_secret_key_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut keypair = Keypair::new();
+        assert_ne!(keypair.to_bytes(), [0u8; BLS_SECRET_KEY_SIZE]);
+        keypair.secret.zeroize();
+        assert_eq!(keypair.to_bytes(), [0u8; BLS_SECRET_KEY_SIZE]);
+    }
+
+    #[test]
+    fn test_keygen_from_seed() {
+        let seed = [7u8; BLS_SEED_MIN_SIZE];
+        let keypair = Keypair::from_seed(&seed).unwrap();
+        assert_eq!(keypair, Keypair::from_seed(&seed).unwrap());
+        assert_eq!(keypair, Keypair::derive(&seed).unwrap());
+        assert_ne!(
+            keypair,
+            Keypair::from_seed(&[8u8; BLS_SEED_MIN_SIZE]).unwrap()
+        );
+        assert_ne!(keypair, Keypair::from_seed(&[7u8; 64]).unwrap());
+        assert!(keypair
+            .verify(&keypair.sign(b"message"), b"message")
+            .unwrap());
+
+        // short seeds would derive the zero secret key
+        assert_eq!(
+            Keypair::from_seed(&seed[..BLS_SEED_MIN_SIZE - 1]),
+            Err(BlsError::KeyDerivation)
+        );
+        assert_eq!(Keypair::from_seed(&[]), Err(BlsError::KeyDerivation));
+    }
+
     #[test]
     #[cfg(feature = "solana-signer-derive")]
     fn test_keygen_derive_from_signer() {
@@ -6,16 +6,18 @@ extern crate alloc;
 
 #[cfg(feature = "std")]
 extern crate std;
+#[cfg(all(not(target_os = "solana"), feature = "rand"))]
+pub use crate::proof_of_possession::verify_proofs_of_possession;
 #[cfg(not(target_os = "solana"))]
 pub use crate::{
-    error::BlsError,
+    error::{BlsError, PointError},
     keypair::Keypair,
     proof_of_possession::{
         AsProofOfPossessionProjective, ProofOfPossessionProjective, VerifiableProofOfPossession,
     },
     pubkey::{AsPubkeyProjective, PubkeyProjective, VerifiablePubkey},
-    secret_key::{SecretKey, BLS_SECRET_KEY_SIZE},
-    signature::{AsSignatureProjective, SignatureProjective, VerifiableSignature},
+    secret_key::{SecretKey, BLS_SECRET_KEY_SIZE, BLS_SEED_MIN_SIZE},
+    signature::{Aggregator, AsSignatureProjective, SignatureProjective, VerifiableSignature},
 };
 pub use crate::{
     proof_of_possession::{
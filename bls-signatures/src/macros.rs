@@ -23,6 +23,44 @@ macro_rules! impl_from_str {
     };
 }
 
+/// A macro to implement hex encoding and decoding of the underlying bytes as an
+/// alternative to the base64 `Display` and `FromStr` implementations.
+macro_rules! impl_hex {
+    (TYPE = $type:ident, BYTES_LEN = $bytes_len:expr) => {
+        impl $type {
+            /// Encode the bytes as a lowercase hex string
+            pub fn to_hex(&self) -> alloc::string::String {
+                use core::fmt::Write;
+
+                let mut hex = alloc::string::String::with_capacity($bytes_len * 2);
+                for byte in self.0.iter() {
+                    // writing to a `String` cannot fail
+                    let _ = write!(hex, "{byte:02x}");
+                }
+                hex
+            }
+
+            /// Decode the bytes from a hex string with an optional `0x` prefix
+            pub fn from_hex(s: &str) -> Result<Self, crate::error::BlsError> {
+                let s = s.strip_prefix("0x").unwrap_or(s);
+                if s.len() != $bytes_len * 2 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err(crate::error::BlsError::ParseFromString);
+                }
+                let mut bytes = [0u8; $bytes_len];
+                for (byte, pair) in bytes.iter_mut().zip(s.as_bytes().chunks_exact(2)) {
+                    // all ascii hex digits, so both the utf8 and the radix
+                    // conversions succeed
+                    *byte = core::str::from_utf8(pair)
+                        .ok()
+                        .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                        .ok_or(crate::error::BlsError::ParseFromString)?;
+                }
+                Ok($type(bytes))
+            }
+        }
+    };
+}
+
 /// A macro to implement the standard set of conversions between BLS projective,
 /// affine, and compressed point representations.
 ///
@@ -44,6 +82,85 @@ macro_rules! impl_bls_conversions {
         $as_projective_trait:ident,
         $as_affine_trait:ident
     ) => {
+        impl $affine {
+            /// Check that the bytes encode a valid curve point, reporting why
+            /// they do not
+            ///
+            /// Conversions from this type fail with
+            /// [`BlsError::PointConversion`]
+            /// for exactly the inputs rejected here.
+            pub fn validate_point(&self) -> Result<(), crate::error::PointError> {
+                self.decode_point().map(|_| ())
+            }
+
+            /// Decode the underlying curve point, reporting bytes that are not
+            /// a curve point as [`PointError::Decompression`] and points
+            /// outside the prime-order subgroup as [`PointError::NotInSubgroup`].
+            ///
+            /// [`PointError::Decompression`]: crate::error::PointError::Decompression
+            /// [`PointError::NotInSubgroup`]: crate::error::PointError::NotInSubgroup
+            pub(crate) fn decode_point(&self) -> Result<$point_type, crate::error::PointError> {
+                let maybe_point: Option<$point_type> =
+                    <$point_type>::from_uncompressed_unchecked(&self.0).into();
+                let point = maybe_point
+                    .filter(|point| bool::from(point.is_on_curve()))
+                    .ok_or(crate::error::PointError::Decompression)?;
+                if bool::from(point.is_torsion_free()) {
+                    Ok(point)
+                } else {
+                    Err(crate::error::PointError::NotInSubgroup)
+                }
+            }
+
+            /// Convert to the compressed representation, checking that the
+            /// affine bytes are the canonical encoding of the point they
+            /// decode to.
+            ///
+            /// Malformed affine bytes are rejected with the same errors as
+            /// the `TryFrom` conversions. If the compressed point does not
+            /// decompress back to exactly these affine bytes,
+            /// [`BlsError::PointConversion`] is returned.
+            pub fn try_to_compressed(&self) -> Result<$compressed, crate::error::BlsError> {
+                let compressed = $compressed(self.decode_point()?.to_compressed());
+                if compressed.decode_point()?.to_uncompressed() == self.0 {
+                    Ok(compressed)
+                } else {
+                    Err(crate::error::BlsError::PointConversion)
+                }
+            }
+        }
+
+        impl $compressed {
+            /// Check that the bytes encode a valid compressed curve point,
+            /// reporting why they do not
+            ///
+            /// Conversions from this type fail with
+            /// [`BlsError::PointConversion`]
+            /// for exactly the inputs rejected here.
+            pub fn validate_point(&self) -> Result<(), crate::error::PointError> {
+                self.decode_point().map(|_| ())
+            }
+
+            /// Decompress the underlying curve point, reporting bytes that are
+            /// not a curve point as [`PointError::Decompression`] and points
+            /// outside the prime-order subgroup as [`PointError::NotInSubgroup`].
+            ///
+            /// [`PointError::Decompression`]: crate::error::PointError::Decompression
+            /// [`PointError::NotInSubgroup`]: crate::error::PointError::NotInSubgroup
+            pub(crate) fn decode_point(&self) -> Result<$point_type, crate::error::PointError> {
+                let maybe_point: Option<$point_type> =
+                    <$point_type>::from_compressed_unchecked(&self.0).into();
+                let point = maybe_point
+                    .filter(|point| bool::from(point.is_on_curve()))
+                    .ok_or(crate::error::PointError::Decompression)?;
+                if bool::from(point.is_torsion_free()) {
+                    Ok(point)
+                } else {
+                    Err(crate::error::PointError::NotInSubgroup)
+                }
+            }
+        }
+
         // ---
         // infallible conversions from the projective type.
         // ---
@@ -67,9 +184,7 @@ macro_rules! impl_bls_conversions {
             type Error = crate::error::BlsError;
 
             fn try_from(affine: &$affine) -> Result<Self, Self::Error> {
-                let maybe_point: Option<$point_type> =
-                    <$point_type>::from_uncompressed(&affine.0).into();
-                let point = maybe_point.ok_or(crate::error::BlsError::PointConversion)?;
+                let point = affine.decode_point()?;
                 Ok(Self(point.into()))
             }
         }
@@ -86,9 +201,7 @@ macro_rules! impl_bls_conversions {
             type Error = crate::error::BlsError;
 
             fn try_from(compressed: &$compressed) -> Result<Self, Self::Error> {
-                let maybe_point: Option<$point_type> =
-                    <$point_type>::from_compressed(&compressed.0).into();
-                let point = maybe_point.ok_or(crate::error::BlsError::PointConversion)?;
+                let point = compressed.decode_point()?;
                 Ok(Self(point.into()))
             }
         }
@@ -108,9 +221,7 @@ macro_rules! impl_bls_conversions {
             type Error = crate::error::BlsError;
 
             fn try_from(affine: &$affine) -> Result<Self, Self::Error> {
-                let maybe_point: Option<$point_type> =
-                    <$point_type>::from_uncompressed(&affine.0).into();
-                let point = maybe_point.ok_or(crate::error::BlsError::PointConversion)?;
+                let point = affine.decode_point()?;
                 Ok(Self(point.to_compressed()))
             }
         }
@@ -127,9 +238,7 @@ macro_rules! impl_bls_conversions {
             type Error = crate::error::BlsError;
 
             fn try_from(compressed: &$compressed) -> Result<Self, Self::Error> {
-                let maybe_point: Option<$point_type> =
-                    <$point_type>::from_compressed(&compressed.0).into();
-                let point = maybe_point.ok_or(crate::error::BlsError::PointConversion)?;
+                let point = compressed.decode_point()?;
                 Ok(Self(point.to_uncompressed()))
             }
         }
@@ -180,5 +289,24 @@ macro_rules! impl_bls_conversions {
                 $affine::try_from(self)
             }
         }
+
+        // ---
+        // Serde support for the projective type, which goes over the wire in
+        // the compressed representation.
+        // ---
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $projective {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $compressed(self.0.to_compressed()).serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $projective {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let compressed = $compressed::deserialize(deserializer)?;
+                $projective::try_from(&compressed).map_err(serde::de::Error::custom)
+            }
+        }
     };
 }
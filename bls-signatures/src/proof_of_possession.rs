@@ -5,6 +5,16 @@ use {
     crate::{error::BlsError, pubkey::VerifiablePubkey},
     blstrs::{G2Affine, G2Projective},
 };
+#[cfg(all(not(target_os = "solana"), feature = "rand"))]
+use {
+    crate::{
+        hash::hash_pubkey_to_g2,
+        pubkey::{Pubkey, PubkeyProjective},
+        signature::{random_batch_scalar, verify_distinct_prepared},
+    },
+    blstrs::{G1Affine, G2Prepared},
+    group::Group,
+};
 use {
     base64::{prelude::BASE64_STANDARD, Engine},
     core::fmt,
@@ -74,6 +84,46 @@ impl_bls_conversions!(
     AsProofOfPossession
 );
 
+/// Verify a batch of `(public key, proof of possession)` pairs at once.
+///
+/// Each pair is scaled by an independent random 64-bit scalar, so the whole
+/// batch is checked with a single multi-pairing instead of one pairing check
+/// per proof. Returns `Ok(true)` only if every proof is valid for its public
+/// key. A `false` result does not identify which proof failed; fall back to
+/// [`VerifiableProofOfPossession::verify`] to find it.
+///
+/// Returns [`BlsError::EmptyAggregation`] if `entries` is empty, and
+/// propagates decoding errors for malformed public keys or proofs.
+#[cfg(all(not(target_os = "solana"), feature = "rand"))]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn verify_proofs_of_possession(
+    entries: &[(&Pubkey, &ProofOfPossession)],
+) -> Result<bool, BlsError> {
+    if entries.is_empty() {
+        return Err(BlsError::EmptyAggregation);
+    }
+
+    let mut aggregate_proof = G2Projective::identity();
+    let mut pubkeys_affine = alloc::vec::Vec::with_capacity(entries.len());
+    let mut prepared_hashes = alloc::vec::Vec::with_capacity(entries.len());
+    for (pubkey, proof) in entries {
+        let pubkey_projective = PubkeyProjective::try_from(*pubkey)?;
+        let proof_projective = ProofOfPossessionProjective::try_from(*proof)?;
+        let scalar = random_batch_scalar();
+
+        let hashed_pubkey: G2Affine = hash_pubkey_to_g2(&pubkey_projective).into();
+        pubkeys_affine.push(G1Affine::from(pubkey_projective.0 * scalar));
+        prepared_hashes.push(G2Prepared::from(hashed_pubkey));
+        aggregate_proof += proof_projective.0 * scalar;
+    }
+
+    Ok(verify_distinct_prepared(
+        &pubkeys_affine,
+        &prepared_hashes,
+        aggregate_proof.into(),
+    ))
+}
+
 /// A serialized BLS signature in a compressed point representation
 #[cfg_attr(feature = "frozen-abi", derive(solana_frozen_abi_macro::AbiExample))]
 #[cfg_attr(feature = "serde", cfg_eval::cfg_eval, serde_as)]
@@ -157,7 +207,7 @@ mod bytemuck_impls {
     unsafe impl PodInOption for ProofOfPossession {}
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "rand"))]
 mod tests {
     use {
         super::*,
@@ -194,6 +244,46 @@ mod tests {
         assert!(proof_compressed.verify(&pubkey_compressed).unwrap());
     }
 
+    #[test]
+    fn test_verify_proofs_of_possession() {
+        let keypairs: std::vec::Vec<Keypair> = (0..4).map(|_| Keypair::new()).collect();
+        let proofs: std::vec::Vec<ProofOfPossession> = keypairs
+            .iter()
+            .map(|keypair| keypair.proof_of_possession().into())
+            .collect();
+        let mut entries: std::vec::Vec<(&Pubkey, &ProofOfPossession)> = keypairs
+            .iter()
+            .map(|keypair| &keypair.public)
+            .zip(proofs.iter())
+            .collect();
+        assert_eq!(verify_proofs_of_possession(&entries), Ok(true));
+        assert_eq!(verify_proofs_of_possession(&entries[..1]), Ok(true));
+
+        // a proof paired with the wrong public key fails the whole batch
+        entries.swap(0, 1);
+        entries[0].1 = &proofs[0];
+        entries[1].1 = &proofs[1];
+        assert_eq!(verify_proofs_of_possession(&entries), Ok(false));
+
+        // a proof that is a valid signature over a message, rather than over
+        // the public key, is rejected
+        let signature_as_proof =
+            ProofOfPossession(crate::signature::Signature::from(keypairs[0].sign(b"message")).0);
+        assert_eq!(
+            verify_proofs_of_possession(&[(&keypairs[0].public, &signature_as_proof)]),
+            Ok(false)
+        );
+
+        assert_eq!(
+            verify_proofs_of_possession(&[(&Pubkey::default(), &proofs[0])]),
+            Err(BlsError::PointConversion)
+        );
+        assert_eq!(
+            verify_proofs_of_possession(&[]),
+            Err(BlsError::EmptyAggregation)
+        );
+    }
+
     #[test]
     fn proof_of_possession_from_str() {
         let proof_of_possession = ProofOfPossession([1; BLS_PROOF_OF_POSSESSION_AFFINE_SIZE]);
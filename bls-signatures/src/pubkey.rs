@@ -8,7 +8,7 @@ use std::sync::LazyLock;
 use {
     crate::{
         error::BlsError,
-        hash::{hash_message_to_point, hash_pubkey_to_g2},
+        hash::{hash_message_to_point_with_dst, hash_pubkey_to_g2, HASH_TO_POINT_DST},
         proof_of_possession::{AsProofOfPossession, ProofOfPossession},
         secret_key::SecretKey,
         signature::{AsSignature, Signature},
@@ -97,12 +97,30 @@ impl PubkeyProjective {
         Self(G1Projective::identity())
     }
 
+    /// The underlying `blstrs` curve point, for building custom pairing
+    /// equations on top of this crate
+    pub fn as_g1(&self) -> &G1Projective {
+        &self.0
+    }
+
+    /// Consume the public key, returning the underlying `blstrs` curve point
+    pub fn into_inner(self) -> G1Projective {
+        self.0
+    }
+
     /// Construct a corresponding `BlsPubkey` for a `BlsSecretKey`
     #[allow(clippy::arithmetic_side_effects)]
     pub fn from_secret(secret: &SecretKey) -> Self {
         Self(G1Projective::generator() * secret.0)
     }
 
+    /// Prepare the public key for verifying many signatures
+    ///
+    /// See [`PreparedPubkey`].
+    pub fn prepare(&self) -> PreparedPubkey {
+        PreparedPubkey(self.0.into())
+    }
+
     /// Aggregate a list of public keys into an existing aggregate
     #[allow(clippy::arithmetic_side_effects)]
     pub fn aggregate_with<'a, P: AsPubkeyProjective + ?Sized + 'a>(
@@ -115,6 +133,22 @@ impl PubkeyProjective {
         Ok(())
     }
 
+    /// Remove a list of public keys from an existing aggregate
+    ///
+    /// This is the inverse of `aggregate_with`: each removed pubkey must have
+    /// been aggregated before, otherwise the result is meaningless. If any
+    /// pubkey fails to convert, the aggregate is left unchanged.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn aggregate_remove<'a, P: AsPubkeyProjective + ?Sized + 'a>(
+        &mut self,
+        pubkeys: impl Iterator<Item = &'a P>,
+    ) -> Result<(), BlsError> {
+        let mut removed = Self::identity();
+        removed.aggregate_with(pubkeys)?;
+        self.0 -= removed.0;
+        Ok(())
+    }
+
     /// Aggregate a list of public keys
     #[allow(clippy::arithmetic_side_effects)]
     pub fn aggregate<'a, P: AsPubkeyProjective + ?Sized + 'a>(
@@ -162,6 +196,75 @@ impl PubkeyProjective {
 #[cfg(not(target_os = "solana"))]
 impl<T: AsPubkey> VerifiablePubkey for T {}
 
+/// A BLS public key that has been decoded and validated once, for verifying
+/// many signatures against the same key.
+///
+/// Verifying through [`VerifiablePubkey::verify_signature`] converts the
+/// public key to its serialized affine form and decodes it again, including
+/// the subgroup check, on every call. A `PreparedPubkey` holds the decoded
+/// affine point, so each [`PreparedPubkey::verify`] only pays for decoding
+/// the signature and the pairing itself.
+///
+/// The public key is the G1 input of the pairing, and only G2 inputs can be
+/// precomputed for the Miller loop. The saving is therefore limited to the
+/// public key conversion. The pairing dominates the cost of a verification,
+/// so expect a small gain, not a multiple. The `prepared_pubkey` benchmark
+/// compares both paths.
+#[cfg(not(target_os = "solana"))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PreparedPubkey(G1Affine);
+
+#[cfg(not(target_os = "solana"))]
+impl PreparedPubkey {
+    /// Verify a signature and a message against the prepared public key
+    pub fn verify<S: AsSignature>(&self, signature: &S, message: &[u8]) -> Result<bool, BlsError> {
+        let signature_affine = signature.try_as_affine()?.decode_point()?;
+        Ok(verify_signature_points(&self.0, &signature_affine, message))
+    }
+}
+
+/// Verify a signature and a message against a public key, given as decoded
+/// curve points
+#[cfg(not(target_os = "solana"))]
+fn verify_signature_points(
+    pubkey_affine: &G1Affine,
+    signature_affine: &G2Affine,
+    message: &[u8],
+) -> bool {
+    verify_signature_points_with_dst(pubkey_affine, signature_affine, message, HASH_TO_POINT_DST)
+}
+
+/// Verify a signature and a message against a public key, given as decoded
+/// curve points, hashing the message under the domain separation tag `dst`
+#[cfg(not(target_os = "solana"))]
+pub(crate) fn verify_signature_points_with_dst(
+    pubkey_affine: &G1Affine,
+    signature_affine: &G2Affine,
+    message: &[u8],
+    dst: &[u8],
+) -> bool {
+    // The verification equation is e(pubkey, H(m)) = e(g1, signature).
+    // This can be rewritten as e(pubkey, H(m)) * e(-g1, signature) = 1, which
+    // allows for a more efficient verification using a multi-miller loop.
+    let hashed_message: G2Affine = hash_message_to_point_with_dst(message, dst).into();
+    let hashed_message_prepared = G2Prepared::from(hashed_message);
+    let signature_prepared = G2Prepared::from(*signature_affine);
+
+    // use the static valud if `std` is available, otherwise compute it
+    #[cfg(feature = "std")]
+    let neg_g1_generator = &NEG_G1_GENERATOR_AFFINE;
+    #[cfg(not(feature = "std"))]
+    let neg_g1_generator_val: G1Affine = (-G1Projective::generator()).into();
+    #[cfg(not(feature = "std"))]
+    let neg_g1_generator = &neg_g1_generator_val;
+
+    let miller_loop_result = Bls12::multi_miller_loop(&[
+        (pubkey_affine, &hashed_message_prepared),
+        (neg_g1_generator, &signature_prepared),
+    ]);
+    miller_loop_result.final_exponentiation() == Gt::identity()
+}
+
 #[cfg(not(target_os = "solana"))]
 impl_bls_conversions!(
     PubkeyProjective,
@@ -226,6 +329,11 @@ impl_from_str!(
     BASE64_LEN = BLS_PUBLIC_KEY_COMPRESSED_BASE64_SIZE
 );
 
+impl_hex!(
+    TYPE = PubkeyCompressed,
+    BYTES_LEN = BLS_PUBLIC_KEY_COMPRESSED_SIZE
+);
+
 /// A serialized BLS public key in an affine point representation
 #[cfg_attr(feature = "frozen-abi", derive(solana_frozen_abi_macro::AbiExample))]
 #[cfg_attr(feature = "serde", cfg_eval::cfg_eval, serde_as)]
@@ -250,27 +358,7 @@ impl Pubkey {
         else {
             return false;
         };
-
-        // The verification equation is e(pubkey, H(m)) = e(g1, signature).
-        // This can be rewritten as e(pubkey, H(m)) * e(-g1, signature) = 1, which
-        // allows for a more efficient verification using a multi-miller loop.
-        let hashed_message: G2Affine = hash_message_to_point(message).into();
-        let hashed_message_prepared = G2Prepared::from(hashed_message);
-        let signature_prepared = G2Prepared::from(signature_affine);
-
-        // use the static valud if `std` is available, otherwise compute it
-        #[cfg(feature = "std")]
-        let neg_g1_generator = &NEG_G1_GENERATOR_AFFINE;
-        #[cfg(not(feature = "std"))]
-        let neg_g1_generator_val: G1Affine = (-G1Projective::generator()).into();
-        #[cfg(not(feature = "std"))]
-        let neg_g1_generator = &neg_g1_generator_val;
-
-        let miller_loop_result = Bls12::multi_miller_loop(&[
-            (&pubkey_affine, &hashed_message_prepared),
-            (neg_g1_generator, &signature_prepared),
-        ]);
-        miller_loop_result.final_exponentiation() == Gt::identity()
+        verify_signature_points(&pubkey_affine, &signature_affine, message)
     }
 
     /// Verify a proof of possession against a public key
@@ -329,6 +417,8 @@ impl_from_str!(
     BASE64_LEN = BLS_PUBLIC_KEY_AFFINE_BASE64_SIZE
 );
 
+impl_hex!(TYPE = Pubkey, BYTES_LEN = BLS_PUBLIC_KEY_AFFINE_SIZE);
+
 // Byte arrays are both `Pod` and `Zeraoble`, but the traits `bytemuck::Pod` and
 // `bytemuck::Zeroable` can only be derived for power-of-two length byte arrays.
 // Directly implement these traits for types that are simple wrappers around
@@ -347,7 +437,7 @@ mod bytemuck_impls {
     unsafe impl PodInOption for Pubkey {}
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "rand"))]
 mod tests {
     use {
         super::*,
@@ -360,6 +450,39 @@ mod tests {
         std::string::ToString,
     };
 
+    #[test]
+    fn test_pubkey_try_to_compressed() {
+        let keypair = Keypair::new();
+        let affine: Pubkey = keypair.public;
+        let compressed: PubkeyCompressed = affine.try_into().unwrap();
+        assert_eq!(affine.try_to_compressed(), Ok(compressed));
+
+        // a corrupted y coordinate no longer describes a point on the curve
+        let mut bad_coordinate = affine;
+        let last = bad_coordinate.0.len() - 1;
+        bad_coordinate.0[last] ^= 1;
+        assert_eq!(
+            bad_coordinate.try_to_compressed(),
+            Err(BlsError::PointConversion)
+        );
+
+        // with the compression flag set, the bytes are decoded as a
+        // compressed x coordinate and the y coordinate is ignored, so the
+        // plain conversion succeeds, possibly with the negated point
+        let mut bad_flag = affine;
+        bad_flag.0[0] |= 0x80;
+        assert!(PubkeyCompressed::try_from(bad_flag).is_ok());
+        assert_eq!(bad_flag.try_to_compressed(), Err(BlsError::PointConversion));
+
+        // the infinity flag contradicts the non-zero coordinates
+        let mut bad_infinity = affine;
+        bad_infinity.0[0] |= 0x40;
+        assert_eq!(
+            bad_infinity.try_to_compressed(),
+            Err(BlsError::PointConversion)
+        );
+    }
+
     #[test]
     fn test_pubkey_verify_signature() {
         let keypair = Keypair::new();
@@ -404,6 +527,42 @@ mod tests {
             .unwrap());
     }
 
+    #[test]
+    fn test_prepared_pubkey_verify() {
+        let keypair = Keypair::new();
+        let pubkey_projective: PubkeyProjective = (&keypair.public).try_into().unwrap();
+        let prepared = pubkey_projective.prepare();
+        let other_keypair = Keypair::new();
+
+        for message in [&b"test message"[..], b"", b"another message"] {
+            let signature_projective = keypair.sign(message);
+            let signature_affine: Signature = signature_projective.into();
+            let signature_compressed: SignatureCompressed = signature_affine.try_into().unwrap();
+            let other_signature = other_keypair.sign(message);
+
+            for (signature, expected) in [(signature_affine, true), (other_signature.into(), false)]
+            {
+                assert_eq!(prepared.verify(&signature, message), Ok(expected));
+                assert_eq!(
+                    pubkey_projective.verify_signature(&signature, message),
+                    Ok(expected)
+                );
+            }
+            assert_eq!(prepared.verify(&signature_projective, message), Ok(true));
+            assert_eq!(prepared.verify(&signature_compressed, message), Ok(true));
+            assert_eq!(
+                prepared.verify(&signature_affine, b"wrong message"),
+                Ok(false)
+            );
+        }
+
+        let malformed = Signature([0xff; crate::signature::BLS_SIGNATURE_AFFINE_SIZE]);
+        assert_eq!(
+            prepared.verify(&malformed, b"test message"),
+            Err(BlsError::PointConversion)
+        );
+    }
+
     #[test]
     fn test_pubkey_verify_proof_of_possession() {
         let keypair = Keypair::new();
@@ -447,6 +606,33 @@ mod tests {
             .unwrap());
     }
 
+    #[test]
+    fn test_pubkey_aggregate_remove() {
+        let keypairs: std::vec::Vec<_> = (0..3).map(|_| Keypair::new()).collect();
+        let mut aggregate =
+            PubkeyProjective::aggregate(keypairs.iter().map(|keypair| &keypair.public)).unwrap();
+        aggregate
+            .aggregate_remove([&keypairs[1].public].into_iter())
+            .unwrap();
+        assert_eq!(
+            aggregate,
+            PubkeyProjective::aggregate([&keypairs[0].public, &keypairs[2].public].into_iter())
+                .unwrap()
+        );
+
+        let pubkey0_compressed: PubkeyCompressed = keypairs[0].public.try_into().unwrap();
+        aggregate
+            .aggregate_remove(
+                [
+                    &pubkey0_compressed as &dyn AsPubkeyProjective,
+                    &keypairs[2].public,
+                ]
+                .into_iter(),
+            )
+            .unwrap();
+        assert_eq!(aggregate, PubkeyProjective::identity());
+    }
+
     #[test]
     fn test_pubkey_aggregate_dyn() {
         let keypair0 = Keypair::new();
@@ -481,6 +667,49 @@ mod tests {
         assert_eq!(pubkey_compressed, pubkey_compressed_from_string);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_and_deserialize_pubkey_projective() {
+        let original: PubkeyProjective = (&Keypair::new().public).try_into().unwrap();
+        let serialized = bincode::serialize(&original).unwrap();
+        let compressed: PubkeyCompressed = Pubkey::from(original).try_into().unwrap();
+        assert_eq!(serialized, bincode::serialize(&compressed).unwrap());
+        let deserialized: PubkeyProjective = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(original, deserialized);
+
+        let malformed = PubkeyCompressed([0xff; BLS_PUBLIC_KEY_COMPRESSED_SIZE]);
+        assert!(
+            bincode::deserialize::<PubkeyProjective>(&bincode::serialize(&malformed).unwrap())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn pubkey_hex() {
+        let pubkey_affine = Keypair::new().public;
+        let hex = pubkey_affine.to_hex();
+        assert_eq!(hex.len(), BLS_PUBLIC_KEY_AFFINE_SIZE * 2);
+        assert_eq!(Pubkey::from_hex(&hex), Ok(pubkey_affine));
+        assert_eq!(
+            Pubkey::from_hex(&std::format!("0x{hex}")),
+            Ok(pubkey_affine)
+        );
+
+        let pubkey_compressed: PubkeyCompressed = pubkey_affine.try_into().unwrap();
+        assert_eq!(
+            PubkeyCompressed::from_hex(&pubkey_compressed.to_hex()),
+            Ok(pubkey_compressed)
+        );
+        assert_eq!(
+            PubkeyCompressed::from_hex(&hex),
+            Err(BlsError::ParseFromString)
+        );
+        assert_eq!(
+            Pubkey::from_hex(&std::format!("g{}", &hex[1..])),
+            Err(BlsError::ParseFromString)
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serialize_and_deserialize_pubkey() {
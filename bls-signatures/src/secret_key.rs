@@ -1,3 +1,5 @@
+#[cfg(feature = "rand")]
+use rand::rngs::OsRng;
 use {
     crate::{
         error::BlsError,
@@ -10,7 +12,6 @@ use {
     blstrs::Scalar,
     core::ptr,
     ff::Field,
-    rand::rngs::OsRng,
 };
 #[cfg(feature = "solana-signer-derive")]
 use {solana_signature::Signature, solana_signer::Signer, subtle::ConstantTimeEq};
@@ -18,6 +19,10 @@ use {solana_signature::Signature, solana_signer::Signer, subtle::ConstantTimeEq}
 /// Size of BLS secret key in bytes
 pub const BLS_SECRET_KEY_SIZE: usize = 32;
 
+/// Minimum size of the seed (input key material) accepted by
+/// [`SecretKey::from_seed`], as required by the IETF BLS `KeyGen` procedure
+pub const BLS_SEED_MIN_SIZE: usize = 32;
+
 /// A BLS secret key
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SecretKey(pub(crate) Scalar);
@@ -25,6 +30,7 @@ pub struct SecretKey(pub(crate) Scalar);
 impl SecretKey {
     /// Constructs a new, random `BlsSecretKey` using `OsRng`
     #[allow(clippy::new_without_default)]
+    #[cfg(feature = "rand")]
     pub fn new() -> Self {
         let mut rng = OsRng;
         Self(Scalar::random(&mut rng))
@@ -48,6 +54,23 @@ impl SecretKey {
             .map_err(|_| BlsError::FieldDecode)
     }
 
+    /// Deterministically derive a `BlsSecretKey` from a seed using the IETF
+    /// BLS `KeyGen` procedure (HKDF-based hash-to-field).
+    ///
+    /// Unlike [`SecretKey::derive`], which silently produces a zero secret
+    /// key for short input, this rejects seeds shorter than
+    /// [`BLS_SEED_MIN_SIZE`] bytes with [`BlsError::KeyDerivation`].
+    pub fn from_seed(seed: &[u8]) -> Result<Self, BlsError> {
+        if seed.len() < BLS_SEED_MIN_SIZE {
+            return Err(BlsError::KeyDerivation);
+        }
+        let secret = Self::derive(seed)?;
+        if bool::from(secret.0.is_zero()) {
+            return Err(BlsError::KeyDerivation);
+        }
+        Ok(secret)
+    }
+
     /// Derive a `BlsSecretKey` from a Solana signer
     #[cfg(feature = "solana-signer-derive")]
     pub fn derive_from_signer(signer: &dyn Signer, public_seed: &[u8]) -> Result<Self, BlsError> {
@@ -93,6 +116,27 @@ impl TryFrom<&[u8]> for SecretKey {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for SecretKey {
+    fn zeroize(&mut self) {
+        // `blstrs::Scalar` has no `Zeroize` impl, so overwrite it the same way
+        // the `zeroize` crate does: a volatile write that cannot be elided,
+        // followed by a fence that keeps it ordered before the drop
+        unsafe { ptr::write_volatile(&mut self.0, Scalar::ZERO) };
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for SecretKey {}
+
 impl From<&SecretKey> for [u8; BLS_SECRET_KEY_SIZE] {
     fn from(secret_key: &SecretKey) -> Self {
         secret_key.0.to_bytes_le()
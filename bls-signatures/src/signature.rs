@@ -1,17 +1,26 @@
 #[cfg(all(not(target_os = "solana"), feature = "std"))]
 use crate::pubkey::NEG_G1_GENERATOR_AFFINE;
-#[cfg(not(feature = "std"))]
+#[cfg(all(not(target_os = "solana"), not(feature = "std")))]
 use blstrs::G1Projective;
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, PodInOption, Zeroable, ZeroableInOption};
+#[cfg(all(not(target_os = "solana"), feature = "rand"))]
+use {
+    crate::proof_of_possession::{verify_proofs_of_possession, ProofOfPossession},
+    rand::{rngs::OsRng, RngCore},
+};
 #[cfg(not(target_os = "solana"))]
 use {
     crate::{
         error::BlsError,
-        hash::hash_message_to_point,
-        pubkey::{AsPubkeyProjective, Pubkey, PubkeyProjective, VerifiablePubkey},
+        hash::{hash_message_to_point, hash_message_to_point_with_dst},
+        pubkey::{
+            verify_signature_points_with_dst, AsPubkeyProjective, Pubkey, PubkeyProjective,
+            VerifiablePubkey,
+        },
+        secret_key::SecretKey,
     },
-    blstrs::{Bls12, G1Affine, G2Affine, G2Prepared, G2Projective, Gt},
+    blstrs::{Bls12, G1Affine, G2Affine, G2Prepared, G2Projective, Gt, Scalar},
     group::Group,
     pairing::{MillerLoopResult, MultiMillerLoop},
 };
@@ -79,6 +88,56 @@ impl SignatureProjective {
         Self(G2Projective::identity())
     }
 
+    /// The underlying `blstrs` curve point, for building custom pairing
+    /// equations on top of this crate
+    pub fn as_g2(&self) -> &G2Projective {
+        &self.0
+    }
+
+    /// Consume the signature, returning the underlying `blstrs` curve point
+    pub fn into_inner(self) -> G2Projective {
+        self.0
+    }
+
+    /// Sign a message, hashing it to the curve under a custom domain
+    /// separation tag
+    ///
+    /// Signatures made under different tags do not verify against each
+    /// other, so an application can use its own `dst` to keep its signatures
+    /// from being replayed as, e.g., consensus votes, which use the default
+    /// [`HASH_TO_POINT_DST`](crate::hash::HASH_TO_POINT_DST). Verify with
+    /// [`SignatureProjective::verify_with_dst`].
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn sign_with_dst(secret: &SecretKey, message: &[u8], dst: &[u8]) -> Self {
+        Self(hash_message_to_point_with_dst(message, dst) * secret.0)
+    }
+
+    /// Verify a signature made with [`SignatureProjective::sign_with_dst`]
+    /// against any convertible public key type, a message, and the same
+    /// domain separation tag
+    pub fn verify_with_dst<P: AsPubkeyProjective + ?Sized>(
+        &self,
+        pubkey: &P,
+        message: &[u8],
+        dst: &[u8],
+    ) -> Result<bool, BlsError> {
+        let pubkey_affine = G1Affine::from(pubkey.try_as_projective()?.0);
+        Ok(verify_signature_points_with_dst(
+            &pubkey_affine,
+            &self.0.into(),
+            message,
+            dst,
+        ))
+    }
+
+    /// Decode a compressed signature received off the wire
+    ///
+    /// The compressed point is decompressed and checked once, then projected
+    /// directly, without materializing the intermediate affine [`Signature`].
+    pub fn from_compressed(compressed: &SignatureCompressed) -> Result<Self, BlsError> {
+        Self::try_from(compressed)
+    }
+
     /// Aggregate a list of signatures into an existing aggregate
     #[allow(clippy::arithmetic_side_effects)]
     pub fn aggregate_with<'a, S: AsSignatureProjective + ?Sized + 'a>(
@@ -91,6 +150,22 @@ impl SignatureProjective {
         Ok(())
     }
 
+    /// Remove a list of signatures from an existing aggregate
+    ///
+    /// This is the inverse of `aggregate_with`: each removed signature must have
+    /// been aggregated before, otherwise the result is meaningless. If any
+    /// signature fails to convert, the aggregate is left unchanged.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn aggregate_remove<'a, S: AsSignatureProjective + ?Sized + 'a>(
+        &mut self,
+        signatures: impl Iterator<Item = &'a S>,
+    ) -> Result<(), BlsError> {
+        let mut removed = Self::identity();
+        removed.aggregate_with(signatures)?;
+        self.0 -= removed.0;
+        Ok(())
+    }
+
     /// Aggregate a list of signatures
     pub fn aggregate<'a, S: AsSignatureProjective + ?Sized + 'a>(
         mut signatures: impl Iterator<Item = &'a S>,
@@ -105,6 +180,37 @@ impl SignatureProjective {
         }
     }
 
+    /// Aggregate signatures taken by value from any iterable, e.g. as they
+    /// come out of a streaming decoder, without collecting them first
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn aggregate_iter<S: AsSignatureProjective, I: IntoIterator<Item = S>>(
+        signatures: I,
+    ) -> Result<SignatureProjective, BlsError> {
+        let mut signatures = signatures.into_iter();
+        let mut aggregate = signatures
+            .next()
+            .ok_or(BlsError::EmptyAggregation)?
+            .try_as_projective()?;
+        for signature in signatures {
+            aggregate.0 += signature.try_as_projective()?.0;
+        }
+        Ok(aggregate)
+    }
+
+    /// Aggregate signatures borrowed from any iterable
+    ///
+    /// This is the borrowing variant of [`SignatureProjective::aggregate_iter`],
+    /// which also accepts collections such as `&Vec<Signature>` directly.
+    pub fn aggregate_iter_ref<
+        'a,
+        S: AsSignatureProjective + ?Sized + 'a,
+        I: IntoIterator<Item = &'a S>,
+    >(
+        signatures: I,
+    ) -> Result<SignatureProjective, BlsError> {
+        Self::aggregate(signatures.into_iter())
+    }
+
     /// Verify a list of signatures against a message and a list of public keys
     pub fn verify_aggregate<
         'a,
@@ -121,6 +227,154 @@ impl SignatureProjective {
         aggregate_pubkey.verify_signature(&aggregate_signature, message)
     }
 
+    /// Verify signatures over a single message from signers that must each
+    /// prove possession of their secret key
+    ///
+    /// Plain [`SignatureProjective::verify_aggregate`] over one message is
+    /// open to rogue-key attacks, where a signer registers a public key
+    /// derived from the others' keys and forges the aggregate alone. Here
+    /// every proof of possession is checked first, in one batch, and the
+    /// aggregate check runs only if all of them are valid. A single bad
+    /// proof rejects the whole set with `Ok(false)`.
+    ///
+    /// Returns [`BlsError::EmptyAggregation`] if `entries` is empty.
+    #[cfg(feature = "rand")]
+    pub fn aggregate_verify_with_pop(
+        entries: &[(&Pubkey, &ProofOfPossession, &Signature)],
+        message: &[u8],
+    ) -> Result<bool, BlsError> {
+        let proofs: alloc::vec::Vec<_> = entries
+            .iter()
+            .map(|(pubkey, proof, _)| (*pubkey, *proof))
+            .collect();
+        if !verify_proofs_of_possession(&proofs)? {
+            return Ok(false);
+        }
+
+        Self::verify_aggregate(
+            entries.iter().map(|(pubkey, _, _)| *pubkey),
+            entries.iter().map(|(_, _, signature)| *signature),
+            message,
+        )
+    }
+
+    /// Verify a certificate given as an aggregate signature plus a bitmap of
+    /// which validators in a known set signed `message`
+    ///
+    /// Bit `i` of `participation` is bit `i % 8` of byte `i / 8`, counting
+    /// from the least significant bit, and selects `all_pubkeys[i]`. The
+    /// bitmap must be exactly `all_pubkeys.len().div_ceil(8)` bytes long with
+    /// all padding bits clear; otherwise [`BlsError::InputLengthMismatch`] is
+    /// returned. If no bit is set, [`BlsError::EmptyAggregation`] is returned.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn aggregate_verify_bitmap<S: AsSignatureProjective + ?Sized>(
+        all_pubkeys: &[PubkeyProjective],
+        participation: &[u8],
+        aggregate_signature: &S,
+        message: &[u8],
+    ) -> Result<bool, BlsError> {
+        if participation.len() != all_pubkeys.len().div_ceil(8) {
+            return Err(BlsError::InputLengthMismatch);
+        }
+        let padding_bits = all_pubkeys.len() % 8;
+        if padding_bits != 0
+            && participation
+                .last()
+                .is_some_and(|byte| byte >> padding_bits != 0)
+        {
+            return Err(BlsError::InputLengthMismatch);
+        }
+
+        let mut aggregate_pubkey = PubkeyProjective::identity();
+        let mut signers = 0usize;
+        for (i, pubkey) in all_pubkeys.iter().enumerate() {
+            if participation[i / 8] & (1 << (i % 8)) != 0 {
+                aggregate_pubkey.0 += pubkey.0;
+                signers += 1;
+            }
+        }
+        if signers == 0 {
+            return Err(BlsError::EmptyAggregation);
+        }
+
+        aggregate_pubkey.verify_signature(&aggregate_signature.try_as_projective()?, message)
+    }
+
+    /// Aggregate a list of signatures, scaling each one by its weight
+    ///
+    /// This produces the aggregate signature checked by
+    /// [`SignatureProjective::weighted_aggregate_verify`], e.g. when votes are
+    /// weighted by stake. Returns [`BlsError::EmptyAggregation`] if
+    /// `weighted_signatures` is empty.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn weighted_aggregate<S: AsSignatureProjective + ?Sized>(
+        weighted_signatures: &[(&S, u64)],
+    ) -> Result<SignatureProjective, BlsError> {
+        if weighted_signatures.is_empty() {
+            return Err(BlsError::EmptyAggregation);
+        }
+        let mut aggregate = Self::identity();
+        for (signature, weight) in weighted_signatures {
+            aggregate.0 += signature.try_as_projective()?.0 * Scalar::from(*weight);
+        }
+        Ok(aggregate)
+    }
+
+    /// Verify an aggregate signature over a single message against a list of
+    /// public keys, each scaled by its weight
+    ///
+    /// The public keys are combined into `sum(w_i * pk_i)` before a single
+    /// pairing check, so `aggregate_signature` must be the matching weighted
+    /// aggregate `sum(w_i * sig_i)`, as produced by
+    /// [`SignatureProjective::weighted_aggregate`].
+    ///
+    /// Returns [`BlsError::EmptyAggregation`] if `weighted_keys` is empty or
+    /// every weight is zero, since there is then no signer to verify.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn weighted_aggregate_verify<
+        P: AsPubkeyProjective + ?Sized,
+        S: AsSignatureProjective + ?Sized,
+    >(
+        weighted_keys: &[(&P, u64)],
+        aggregate_signature: &S,
+        message: &[u8],
+    ) -> Result<bool, BlsError> {
+        let mut aggregate_pubkey = PubkeyProjective::identity();
+        for (pubkey, weight) in weighted_keys {
+            aggregate_pubkey.0 += pubkey.try_as_projective()?.0 * Scalar::from(*weight);
+        }
+        if bool::from(aggregate_pubkey.0.is_identity()) {
+            return Err(BlsError::EmptyAggregation);
+        }
+        aggregate_pubkey.verify_signature(&aggregate_signature.try_as_projective()?, message)
+    }
+
+    /// Verify a sequence of independent aggregate groups, stopping at the
+    /// first group that fails verification.
+    ///
+    /// Each group is a list of public keys, a list of signatures, and the
+    /// message they all signed, and is checked as in
+    /// [`SignatureProjective::verify_aggregate`]. Returns `Ok(false)` as soon
+    /// as a group does not verify, without processing the remaining groups.
+    ///
+    /// Because verification stops early, the running time reveals the position
+    /// of the first invalid group. Only use this when the ordering of the
+    /// groups is not under adversarial control.
+    pub fn verify_groups_short_circuit<
+        'a,
+        P: AsPubkeyProjective + 'a,
+        S: AsSignatureProjective + 'a,
+    >(
+        groups: impl IntoIterator<Item = (&'a [P], &'a [S], &'a [u8])>,
+    ) -> Result<bool, BlsError> {
+        for (public_keys, signatures, message) in groups {
+            if !Self::verify_aggregate(public_keys.iter(), signatures.iter(), message)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
     /// Verifies an aggregated signature over a set of distinct messages and
     /// public keys.
     pub fn verify_distinct<'a>(
@@ -154,11 +408,8 @@ impl SignatureProjective {
 
         // TODO: remove `Vec` allocation if possible for efficiency
         let mut pubkeys_affine = alloc::vec::Vec::with_capacity(public_keys.len());
-        let public_keys_len = public_keys.len();
         for pubkey in public_keys {
-            let maybe_g1_affine: Option<_> = G1Affine::from_uncompressed(&pubkey.0).into();
-            let g1_affine: G1Affine = maybe_g1_affine.ok_or(BlsError::PointConversion)?;
-            pubkeys_affine.push(g1_affine);
+            pubkeys_affine.push(pubkey.decode_point()?);
         }
 
         let mut prepared_hashes = alloc::vec::Vec::with_capacity(messages.len());
@@ -167,27 +418,126 @@ impl SignatureProjective {
             prepared_hashes.push(G2Prepared::from(hashed_message));
         }
 
-        let maybe_aggregate_signature_affine: Option<G2Affine> =
-            G2Affine::from_uncompressed(&aggregate_signature.0).into();
-        let aggregate_signature_affine =
-            maybe_aggregate_signature_affine.ok_or(BlsError::PointConversion)?;
-        let signature_prepared = G2Prepared::from(aggregate_signature_affine);
+        let aggregate_signature_affine = aggregate_signature.decode_point()?;
+        Ok(verify_distinct_prepared(
+            &pubkeys_affine,
+            &prepared_hashes,
+            aggregate_signature_affine,
+        ))
+    }
+
+    /// Verifies an aggregate signature over a set of distinct messages, where
+    /// the `i`-th public key signed the `i`-th message.
+    ///
+    /// This is the generic counterpart of
+    /// [`SignatureProjective::verify_distinct_aggregated`]: the public keys
+    /// and the aggregate signature may be given in any of their
+    /// representations. Returns [`BlsError::EmptyAggregation`] if there are no
+    /// public keys and [`BlsError::InputLengthMismatch`] if the number of
+    /// public keys and messages differ.
+    pub fn aggregate_verify_distinct<P: AsPubkeyProjective + ?Sized, S: AsSignatureProjective>(
+        public_keys: &[&P],
+        messages: &[&[u8]],
+        aggregate_signature: &S,
+    ) -> Result<bool, BlsError> {
+        if public_keys.len() != messages.len() {
+            return Err(BlsError::InputLengthMismatch);
+        }
+        if public_keys.is_empty() {
+            return Err(BlsError::EmptyAggregation);
+        }
+
+        let pubkeys_affine = public_keys
+            .iter()
+            .map(|pubkey| pubkey.try_as_projective().map(|pubkey| pubkey.0.into()))
+            .collect::<Result<alloc::vec::Vec<G1Affine>, _>>()?;
+        let prepared_hashes = messages
+            .iter()
+            .map(|message| G2Prepared::from(G2Affine::from(hash_message_to_point(message))))
+            .collect::<alloc::vec::Vec<_>>();
+        let aggregate_signature_affine = aggregate_signature.try_as_projective()?.0.into();
+
+        Ok(verify_distinct_prepared(
+            &pubkeys_affine,
+            &prepared_hashes,
+            aggregate_signature_affine,
+        ))
+    }
+
+    /// Verify a batch of independent `(public key, message, signature)`
+    /// triples at once.
+    ///
+    /// Each triple is weighted by a fresh random 64-bit scalar `r_i`, and the
+    /// batch is accepted if `e(g1, sum(r_i * sig_i)) == prod(e(r_i * pk_i,
+    /// H(m_i)))`. This needs a single multi-pairing instead of one pairing
+    /// check per signature, so it is much faster than verifying each triple
+    /// on its own. Returns `Ok(true)` only if every signature is valid, up to
+    /// a failure probability of 2^-64; it does not identify which signature
+    /// is invalid.
+    ///
+    /// Returns [`BlsError::EmptyAggregation`] if `items` is empty.
+    #[allow(clippy::arithmetic_side_effects)]
+    #[cfg(feature = "rand")]
+    pub fn batch_verify<P: AsPubkeyProjective + ?Sized, S: AsSignatureProjective + ?Sized>(
+        items: &[(&P, &[u8], &S)],
+    ) -> Result<bool, BlsError> {
+        if items.is_empty() {
+            return Err(BlsError::EmptyAggregation);
+        }
+
+        let mut aggregate_signature = G2Projective::identity();
+        let mut pubkeys_affine = alloc::vec::Vec::with_capacity(items.len());
+        let mut prepared_hashes = alloc::vec::Vec::with_capacity(items.len());
+        for (pubkey, message, signature) in items {
+            let (pubkey_affine, prepared_hash, weighted_signature) =
+                batch_verify_term(*pubkey, message, *signature)?;
+            aggregate_signature += weighted_signature;
+            pubkeys_affine.push(pubkey_affine);
+            prepared_hashes.push(prepared_hash);
+        }
+
+        Ok(verify_distinct_prepared(
+            &pubkeys_affine,
+            &prepared_hashes,
+            aggregate_signature.into(),
+        ))
+    }
 
-        #[cfg(feature = "std")]
-        let neg_g1_generator = &*NEG_G1_GENERATOR_AFFINE;
-        #[cfg(not(feature = "std"))]
-        let neg_g1_generator_val: G1Affine = (-G1Projective::generator()).into();
-        #[cfg(not(feature = "std"))]
-        let neg_g1_generator = &neg_g1_generator_val;
+    /// Verify a batch of independent `(public key, message, signature)`
+    /// triples at once, preparing the terms in parallel.
+    ///
+    /// See [`SignatureProjective::batch_verify`].
+    #[allow(clippy::arithmetic_side_effects)]
+    #[cfg(all(feature = "parallel", feature = "rand"))]
+    pub fn par_batch_verify<
+        P: AsPubkeyProjective + Sync + ?Sized,
+        S: AsSignatureProjective + Sync + ?Sized,
+    >(
+        items: &[(&P, &[u8], &S)],
+    ) -> Result<bool, BlsError> {
+        if items.is_empty() {
+            return Err(BlsError::EmptyAggregation);
+        }
 
-        let mut terms = alloc::vec::Vec::with_capacity(public_keys_len.saturating_add(1));
-        for i in 0..public_keys_len {
-            terms.push((&pubkeys_affine[i], &prepared_hashes[i]));
+        let terms = items
+            .par_iter()
+            .map(|(pubkey, message, signature)| batch_verify_term(*pubkey, message, *signature))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut aggregate_signature = G2Projective::identity();
+        let mut pubkeys_affine = Vec::with_capacity(terms.len());
+        let mut prepared_hashes = Vec::with_capacity(terms.len());
+        for (pubkey_affine, prepared_hash, weighted_signature) in terms {
+            aggregate_signature += weighted_signature;
+            pubkeys_affine.push(pubkey_affine);
+            prepared_hashes.push(prepared_hash);
         }
-        terms.push((neg_g1_generator, &signature_prepared));
 
-        let miller_loop_result = Bls12::multi_miller_loop(&terms);
-        Ok(miller_loop_result.final_exponentiation() == Gt::identity())
+        Ok(verify_distinct_prepared(
+            &pubkeys_affine,
+            &prepared_hashes,
+            aggregate_signature.into(),
+        ))
     }
 
     /// Aggregate a list of signatures into an existing aggregate
@@ -256,6 +606,70 @@ impl SignatureProjective {
         Self::par_verify_distinct_aggregated(public_keys, &aggregate_signature.into(), messages)
     }
 
+    /// Verifies a set of signatures over distinct messages, where the `i`-th
+    /// public key signed the `i`-th message with the `i`-th signature.
+    ///
+    /// This is the parallel counterpart of
+    /// [`SignatureProjective::aggregate_verify_distinct`] that takes the
+    /// individual signatures: decoding the public keys, hashing the messages,
+    /// and aggregating the signatures all run on the `rayon` pool before the
+    /// single multi-pairing check. The public keys and signatures may be given
+    /// in any of their representations. Returns
+    /// [`BlsError::EmptyAggregation`] if there are no public keys and
+    /// [`BlsError::InputLengthMismatch`] if the input lengths differ.
+    #[allow(clippy::arithmetic_side_effects)]
+    #[cfg(feature = "parallel")]
+    pub fn par_aggregate_verify_distinct<
+        P: AsPubkeyProjective + Sync + ?Sized,
+        S: AsSignatureProjective + Sync + ?Sized,
+    >(
+        public_keys: &[&P],
+        messages: &[&[u8]],
+        signatures: &[&S],
+    ) -> Result<bool, BlsError> {
+        if public_keys.len() != messages.len() || public_keys.len() != signatures.len() {
+            return Err(BlsError::InputLengthMismatch);
+        }
+        if public_keys.is_empty() {
+            return Err(BlsError::EmptyAggregation);
+        }
+
+        let (pubkeys_affine_res, (prepared_hashes, aggregate_signature_res)) = rayon::join(
+            || {
+                public_keys
+                    .par_iter()
+                    .map(|pubkey| pubkey.try_as_projective().map(|pubkey| pubkey.0.into()))
+                    .collect::<Result<Vec<G1Affine>, _>>()
+            },
+            || {
+                rayon::join(
+                    || {
+                        messages
+                            .par_iter()
+                            .map(|message| {
+                                G2Prepared::from(G2Affine::from(hash_message_to_point(message)))
+                            })
+                            .collect::<Vec<_>>()
+                    },
+                    || {
+                        signatures
+                            .par_iter()
+                            .map(|signature| signature.try_as_projective().map(|s| s.0))
+                            .try_reduce(G2Projective::identity, |a, b| Ok(a + b))
+                    },
+                )
+            },
+        );
+        let pubkeys_affine = pubkeys_affine_res?;
+        let aggregate_signature = aggregate_signature_res?;
+
+        Ok(verify_distinct_prepared(
+            &pubkeys_affine,
+            &prepared_hashes,
+            aggregate_signature.into(),
+        ))
+    }
+
     /// In parallel, verifies a pre-aggregated signature over a set of distinct
     /// messages and public keys.
     #[cfg(feature = "parallel")]
@@ -276,16 +690,7 @@ impl SignatureProjective {
         // 2. Hash messages into curve points and prepare them for pairing.
         let (pubkeys_affine_res, prepared_hashes_res): (Result<Vec<_>, _>, Result<Vec<_>, _>) =
             rayon::join(
-                || {
-                    public_keys
-                        .par_iter()
-                        .map(|pk| {
-                            let maybe_pubkey_affine: Option<_> =
-                                G1Affine::from_uncompressed(&pk.0).into();
-                            maybe_pubkey_affine.ok_or(BlsError::PointConversion)
-                        })
-                        .collect()
-                },
+                || public_keys.par_iter().map(|pk| pk.decode_point()).collect(),
                 || {
                     messages
                         .par_iter()
@@ -301,28 +706,132 @@ impl SignatureProjective {
         let pubkeys_affine = pubkeys_affine_res?;
         let prepared_hashes = prepared_hashes_res?;
 
-        let maybe_aggregate_signature_affine: Option<G2Affine> =
-            G2Affine::from_uncompressed(&aggregate_signature.0).into();
-        let aggregate_signature_affine =
-            maybe_aggregate_signature_affine.ok_or(BlsError::PointConversion)?;
-        let signature_prepared = G2Prepared::from(aggregate_signature_affine);
+        let aggregate_signature_affine = aggregate_signature.decode_point()?;
+        Ok(verify_distinct_prepared(
+            &pubkeys_affine,
+            &prepared_hashes,
+            aggregate_signature_affine,
+        ))
+    }
+}
+
+/// An incremental signature aggregator that tracks how many signatures have
+/// been folded into the running aggregate
+#[cfg(not(target_os = "solana"))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Aggregator {
+    aggregate: SignatureProjective,
+    count: usize,
+}
+
+#[cfg(not(target_os = "solana"))]
+impl Default for Aggregator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+impl Aggregator {
+    /// Creates an empty aggregator
+    pub fn new() -> Self {
+        Self {
+            aggregate: SignatureProjective::identity(),
+            count: 0,
+        }
+    }
+
+    /// Add a signature to the aggregate
+    ///
+    /// If the signature cannot be converted, the aggregate and the count are
+    /// left unchanged.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn push<S: AsSignatureProjective + ?Sized>(
+        &mut self,
+        signature: &S,
+    ) -> Result<(), BlsError> {
+        self.aggregate.0 += signature.try_as_projective()?.0;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// The running aggregate of all signatures pushed so far
+    pub fn aggregate(&self) -> &SignatureProjective {
+        &self.aggregate
+    }
+
+    /// The number of signatures pushed so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
 
-        #[cfg(feature = "std")]
-        let neg_g1_generator = &*NEG_G1_GENERATOR_AFFINE;
-        #[cfg(not(feature = "std"))]
-        let neg_g1_generator_val: G1Affine = (-G1Projective::generator()).into();
-        #[cfg(not(feature = "std"))]
-        let neg_g1_generator = &neg_g1_generator_val;
+    /// Consume the aggregator, returning the aggregate signature and the
+    /// number of signatures it represents
+    pub fn finish(self) -> (SignatureProjective, usize) {
+        (self.aggregate, self.count)
+    }
+}
 
-        let mut terms = alloc::vec::Vec::with_capacity(public_keys.len() + 1);
-        for i in 0..public_keys.len() {
-            terms.push((&pubkeys_affine[i], &prepared_hashes[i]));
+/// Draw a uniformly random non-zero 64-bit scalar for weighting the terms of a
+/// batch verification.
+///
+/// All 64 bits are random, so a forged term slips through a batch with
+/// probability at most 2^-64.
+#[cfg(all(not(target_os = "solana"), feature = "rand"))]
+pub(crate) fn random_batch_scalar() -> Scalar {
+    loop {
+        let scalar = OsRng.next_u64();
+        if scalar != 0 {
+            return Scalar::from(scalar);
         }
-        terms.push((neg_g1_generator, &signature_prepared));
+    }
+}
+
+/// Weight a batch verification triple by a fresh random non-zero scalar `r`,
+/// returning `r * pk`, the prepared `H(m)` and `r * sig`.
+#[cfg(all(not(target_os = "solana"), feature = "rand"))]
+#[allow(clippy::arithmetic_side_effects)]
+fn batch_verify_term<P: AsPubkeyProjective + ?Sized, S: AsSignatureProjective + ?Sized>(
+    pubkey: &P,
+    message: &[u8],
+    signature: &S,
+) -> Result<(G1Affine, G2Prepared, G2Projective), BlsError> {
+    let scalar = random_batch_scalar();
+    let pubkey_affine = G1Affine::from(pubkey.try_as_projective()?.0 * scalar);
+    let hashed_message: G2Affine = hash_message_to_point(message).into();
+    let weighted_signature = signature.try_as_projective()?.0 * scalar;
+    Ok((
+        pubkey_affine,
+        G2Prepared::from(hashed_message),
+        weighted_signature,
+    ))
+}
 
-        let miller_loop_result = Bls12::multi_miller_loop(&terms);
-        Ok(miller_loop_result.final_exponentiation() == Gt::identity())
+/// Check `e(pk_1, H(m_1)) * ... * e(pk_n, H(m_n)) == e(g1, sig)` with a single
+/// multi-Miller loop, where `prepared_hashes` holds the prepared `H(m_i)`.
+#[cfg(not(target_os = "solana"))]
+pub(crate) fn verify_distinct_prepared(
+    pubkeys_affine: &[G1Affine],
+    prepared_hashes: &[G2Prepared],
+    aggregate_signature_affine: G2Affine,
+) -> bool {
+    let signature_prepared = G2Prepared::from(aggregate_signature_affine);
+
+    #[cfg(feature = "std")]
+    let neg_g1_generator = &*NEG_G1_GENERATOR_AFFINE;
+    #[cfg(not(feature = "std"))]
+    let neg_g1_generator_val: G1Affine = (-G1Projective::generator()).into();
+    #[cfg(not(feature = "std"))]
+    let neg_g1_generator = &neg_g1_generator_val;
+
+    let mut terms = alloc::vec::Vec::with_capacity(pubkeys_affine.len().saturating_add(1));
+    for (pubkey, prepared_hash) in pubkeys_affine.iter().zip(prepared_hashes) {
+        terms.push((pubkey, prepared_hash));
     }
+    terms.push((neg_g1_generator, &signature_prepared));
+
+    let miller_loop_result = Bls12::multi_miller_loop(&terms);
+    miller_loop_result.final_exponentiation() == Gt::identity()
 }
 
 #[cfg(not(target_os = "solana"))]
@@ -367,6 +876,11 @@ impl_from_str!(
     BASE64_LEN = BLS_SIGNATURE_COMPRESSED_BASE64_SIZE
 );
 
+impl_hex!(
+    TYPE = SignatureCompressed,
+    BYTES_LEN = BLS_SIGNATURE_COMPRESSED_SIZE
+);
+
 /// A serialized BLS signature in an affine point representation
 #[cfg_attr(feature = "frozen-abi", derive(solana_frozen_abi_macro::AbiExample))]
 #[cfg_attr(feature = "serde", cfg_eval::cfg_eval, serde_as)]
@@ -396,6 +910,30 @@ impl_from_str!(
     BASE64_LEN = BLS_SIGNATURE_AFFINE_BASE64_SIZE
 );
 
+impl_hex!(TYPE = Signature, BYTES_LEN = BLS_SIGNATURE_AFFINE_SIZE);
+
+// Constant-time comparison of the serialized bytes, for matching signatures in
+// paths where the derived short-circuiting `PartialEq` could leak timing.
+#[cfg(feature = "constant-time")]
+mod constant_time_impls {
+    use {
+        super::*,
+        subtle::{Choice, ConstantTimeEq},
+    };
+
+    impl ConstantTimeEq for Signature {
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.0.ct_eq(&other.0)
+        }
+    }
+
+    impl ConstantTimeEq for SignatureCompressed {
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.0.ct_eq(&other.0)
+        }
+    }
+}
+
 // Byte arrays are both `Pod` and `Zeraoble`, but the traits `bytemuck::Pod` and
 // `bytemuck::Zeroable` can only be derived for power-of-two length byte arrays.
 // Directly implement these traits for types that are simple wrappers around
@@ -415,11 +953,12 @@ mod bytemuck_impls {
     unsafe impl PodInOption for SignatureCompressed {}
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "rand"))]
 mod tests {
     use {
         super::*,
         crate::{
+            error::PointError,
             keypair::Keypair,
             pubkey::{Pubkey, PubkeyCompressed},
         },
@@ -427,6 +966,39 @@ mod tests {
         std::{string::ToString, vec::Vec},
     };
 
+    #[test]
+    fn test_signature_try_to_compressed() {
+        let keypair = Keypair::new();
+        let affine: Signature = keypair.sign(b"test message").into();
+        let compressed: SignatureCompressed = affine.try_into().unwrap();
+        assert_eq!(affine.try_to_compressed(), Ok(compressed));
+
+        // a corrupted y coordinate no longer describes a point on the curve
+        let mut bad_coordinate = affine;
+        let last = bad_coordinate.0.len() - 1;
+        bad_coordinate.0[last] ^= 1;
+        assert_eq!(
+            bad_coordinate.try_to_compressed(),
+            Err(BlsError::PointConversion)
+        );
+
+        // with the compression flag set, the bytes are decoded as a
+        // compressed x coordinate and the y coordinate is ignored, so the
+        // plain conversion succeeds, possibly with the negated point
+        let mut bad_flag = affine;
+        bad_flag.0[0] |= 0x80;
+        assert!(SignatureCompressed::try_from(bad_flag).is_ok());
+        assert_eq!(bad_flag.try_to_compressed(), Err(BlsError::PointConversion));
+
+        // the infinity flag contradicts the non-zero coordinates
+        let mut bad_infinity = affine;
+        bad_infinity.0[0] |= 0x40;
+        assert_eq!(
+            bad_infinity.try_to_compressed(),
+            Err(BlsError::PointConversion)
+        );
+    }
+
     #[test]
     fn test_signature_verification() {
         let keypair = Keypair::new();
@@ -471,6 +1043,59 @@ mod tests {
             .unwrap());
     }
 
+    #[test]
+    fn test_signature_verification_error_contract() {
+        let keypair = Keypair::new();
+        let test_message = b"test message";
+        let signature: Signature = keypair.sign(test_message).into();
+        let signature_compressed: SignatureCompressed = signature.try_into().unwrap();
+
+        // a well-formed signature over a different message does not verify
+        assert_eq!(
+            signature_compressed.verify(&keypair.public, b"other message"),
+            Ok(false)
+        );
+        let other_keypair = Keypair::new();
+        assert_eq!(
+            signature_compressed.verify(&other_keypair.public, test_message),
+            Ok(false)
+        );
+
+        // bytes that do not decompress to a curve point are malformed input
+        let malformed = SignatureCompressed([0xff; BLS_SIGNATURE_COMPRESSED_SIZE]);
+        assert_eq!(
+            malformed.verify(&keypair.public, test_message),
+            Err(BlsError::PointConversion)
+        );
+        assert_eq!(malformed.validate_point(), Err(PointError::Decompression));
+        let malformed = Signature([0xff; BLS_SIGNATURE_AFFINE_SIZE]);
+        assert_eq!(
+            malformed.verify(&keypair.public, test_message),
+            Err(BlsError::PointConversion)
+        );
+        assert_eq!(malformed.validate_point(), Err(PointError::Decompression));
+        assert_eq!(signature.validate_point(), Ok(()));
+        assert_eq!(signature_compressed.validate_point(), Ok(()));
+
+        // almost every point on the G2 curve lies outside the prime-order
+        // subgroup, so some small x-coordinate yields such a point
+        let not_in_subgroup = (1..=u8::MAX)
+            .map(|x| {
+                let mut bytes = [0u8; BLS_SIGNATURE_COMPRESSED_SIZE];
+                bytes[0] = 0x80;
+                bytes[BLS_SIGNATURE_COMPRESSED_SIZE - 1] = x;
+                SignatureCompressed(bytes)
+            })
+            .find(|signature| signature.validate_point() == Err(PointError::NotInSubgroup));
+        assert!(not_in_subgroup.is_some());
+        assert_eq!(
+            not_in_subgroup
+                .unwrap()
+                .verify(&keypair.public, test_message),
+            Err(BlsError::PointConversion)
+        );
+    }
+
     #[test]
     fn test_signature_aggregate() {
         let test_message = b"test message";
@@ -493,6 +1118,47 @@ mod tests {
         assert_eq!(aggregate_signature, aggregate_signature_with);
     }
 
+    #[test]
+    fn test_signature_aggregate_remove() {
+        let test_message = b"test message";
+        let keypairs: Vec<_> = (0..3).map(|_| Keypair::new()).collect();
+        let signatures: Vec<SignatureProjective> = keypairs
+            .iter()
+            .map(|keypair| keypair.sign(test_message))
+            .collect();
+        let signature1_affine: Signature = signatures[1].into();
+
+        let mut aggregate = SignatureProjective::aggregate(signatures.iter()).unwrap();
+        aggregate
+            .aggregate_remove([&signature1_affine].into_iter())
+            .unwrap();
+        assert_eq!(
+            aggregate,
+            SignatureProjective::aggregate([&signatures[0], &signatures[2]].into_iter()).unwrap()
+        );
+        assert!(SignatureProjective::verify_aggregate(
+            [&keypairs[0].public, &keypairs[2].public].into_iter(),
+            [&aggregate].into_iter(),
+            test_message,
+        )
+        .unwrap());
+
+        aggregate
+            .aggregate_remove([&signatures[0], &signatures[2]].into_iter())
+            .unwrap();
+        assert_eq!(aggregate, SignatureProjective::identity());
+
+        // a malformed signature leaves the aggregate unchanged
+        let mut aggregate = SignatureProjective::aggregate(signatures.iter()).unwrap();
+        let before = aggregate;
+        let malformed = Signature([0xff; BLS_SIGNATURE_AFFINE_SIZE]);
+        assert_eq!(
+            aggregate.aggregate_remove([&signature1_affine, &malformed].into_iter()),
+            Err(BlsError::PointConversion)
+        );
+        assert_eq!(aggregate, before);
+    }
+
     #[test]
     fn test_verify_aggregate() {
         let test_message = b"test message";
@@ -571,15 +1237,503 @@ mod tests {
         assert_eq!(err, BlsError::EmptyAggregation);
     }
 
+    #[cfg(feature = "constant-time")]
     #[test]
-    fn test_verify_distinct() {
-        let keypair0 = Keypair::new();
-        let keypair1 = Keypair::new();
-        let keypair2 = Keypair::new();
-
-        let message0 = b"message zero";
-        let message1 = b"message one";
-        let message2 = b"message two";
+    fn test_signature_ct_eq() {
+        use subtle::ConstantTimeEq;
+
+        let keypair = Keypair::new();
+        let signature: Signature = keypair.sign(b"test message").into();
+        let other_signature: Signature = keypair.sign(b"other message").into();
+        assert!(bool::from(signature.ct_eq(&signature)));
+        assert!(!bool::from(signature.ct_eq(&other_signature)));
+
+        let compressed = SignatureCompressed::try_from(signature).unwrap();
+        let other_compressed = SignatureCompressed::try_from(other_signature).unwrap();
+        assert!(bool::from(compressed.ct_eq(&compressed)));
+        assert!(!bool::from(compressed.ct_eq(&other_compressed)));
+    }
+
+    #[test]
+    fn test_signature_from_compressed() {
+        let signature_projective = Keypair::new().sign(b"test message");
+        let signature_affine: Signature = signature_projective.into();
+        let signature_compressed = SignatureCompressed::try_from(signature_affine).unwrap();
+        assert_eq!(
+            SignatureProjective::from_compressed(&signature_compressed),
+            Ok(signature_projective)
+        );
+        assert_eq!(
+            SignatureProjective::from_compressed(&signature_compressed),
+            SignatureProjective::try_from(&signature_affine)
+        );
+
+        assert_eq!(
+            SignatureProjective::from_compressed(&SignatureCompressed::default()),
+            Err(BlsError::PointConversion)
+        );
+    }
+
+    #[test]
+    fn test_sign_with_dst() {
+        let keypair = Keypair::new();
+        let test_message = b"test message";
+        let dst = b"MY_APP_ATTESTATION_BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+        let signature = SignatureProjective::sign_with_dst(&keypair.secret, test_message, dst);
+        assert_eq!(
+            signature.verify_with_dst(&keypair.public, test_message, dst),
+            Ok(true)
+        );
+        assert_eq!(
+            signature.verify_with_dst(&keypair.public, b"other message", dst),
+            Ok(false)
+        );
+        assert_eq!(
+            signature.verify_with_dst(&Keypair::new().public, test_message, dst),
+            Ok(false)
+        );
+
+        // signatures do not cross between the custom and the default tags
+        assert_eq!(signature.verify(&keypair.public, test_message), Ok(false));
+        let default_signature = keypair.sign(test_message);
+        assert_eq!(
+            default_signature.verify_with_dst(&keypair.public, test_message, dst),
+            Ok(false)
+        );
+        assert_eq!(
+            default_signature.verify_with_dst(
+                &keypair.public,
+                test_message,
+                crate::hash::HASH_TO_POINT_DST
+            ),
+            Ok(true)
+        );
+        assert_eq!(
+            SignatureProjective::sign_with_dst(
+                &keypair.secret,
+                test_message,
+                crate::hash::HASH_TO_POINT_DST
+            ),
+            default_signature
+        );
+
+        assert_eq!(
+            signature.verify_with_dst(&Pubkey::default(), test_message, dst),
+            Err(BlsError::PointConversion)
+        );
+    }
+
+    #[test]
+    fn test_aggregate_iter() {
+        let test_message = b"test message";
+        let keypairs: Vec<_> = (0..3).map(|_| Keypair::new()).collect();
+        let signatures: Vec<Signature> = keypairs
+            .iter()
+            .map(|keypair| keypair.sign(test_message).into())
+            .collect();
+        let expected = SignatureProjective::aggregate(signatures.iter()).unwrap();
+
+        // owned signatures straight from a decoding iterator
+        let encoded: Vec<std::string::String> = signatures
+            .iter()
+            .map(|signature| signature.to_string())
+            .collect();
+        assert_eq!(
+            SignatureProjective::aggregate_iter(
+                encoded
+                    .iter()
+                    .map(|encoded| Signature::from_str(encoded).unwrap())
+            ),
+            Ok(expected)
+        );
+        assert_eq!(
+            SignatureProjective::aggregate_iter(signatures.clone()),
+            Ok(expected)
+        );
+        assert_eq!(
+            SignatureProjective::aggregate_iter_ref(&signatures),
+            Ok(expected)
+        );
+
+        assert_eq!(
+            SignatureProjective::aggregate_iter([Signature::default()]),
+            Err(BlsError::PointConversion)
+        );
+        assert_eq!(
+            SignatureProjective::aggregate_iter(Vec::<Signature>::new()),
+            Err(BlsError::EmptyAggregation)
+        );
+        assert_eq!(
+            SignatureProjective::aggregate_iter_ref(&Vec::<Signature>::new()),
+            Err(BlsError::EmptyAggregation)
+        );
+    }
+
+    #[test]
+    fn test_aggregate_verify_bitmap() {
+        let test_message = b"test message";
+        let keypairs: Vec<_> = (0..10).map(|_| Keypair::new()).collect();
+        let all_pubkeys: Vec<PubkeyProjective> = keypairs
+            .iter()
+            .map(|keypair| (&keypair.public).try_into().unwrap())
+            .collect();
+
+        // validators 0, 3, 8 and 9 signed
+        let participation = [0b0000_1001u8, 0b0000_0011];
+        let aggregate_signature = SignatureProjective::aggregate(
+            [0, 3, 8, 9]
+                .iter()
+                .map(|&i| keypairs[i].sign(test_message))
+                .collect::<Vec<_>>()
+                .iter(),
+        )
+        .unwrap();
+        assert_eq!(
+            SignatureProjective::aggregate_verify_bitmap(
+                &all_pubkeys,
+                &participation,
+                &aggregate_signature,
+                test_message
+            ),
+            Ok(true)
+        );
+        assert_eq!(
+            SignatureProjective::aggregate_verify_bitmap(
+                &all_pubkeys,
+                &participation,
+                &aggregate_signature,
+                b"other message"
+            ),
+            Ok(false)
+        );
+
+        // claiming an extra or a missing signer fails
+        assert_eq!(
+            SignatureProjective::aggregate_verify_bitmap(
+                &all_pubkeys,
+                &[0b0000_1011, 0b0000_0011],
+                &aggregate_signature,
+                test_message
+            ),
+            Ok(false)
+        );
+        assert_eq!(
+            SignatureProjective::aggregate_verify_bitmap(
+                &all_pubkeys,
+                &[0b0000_1001, 0b0000_0001],
+                &aggregate_signature,
+                test_message
+            ),
+            Ok(false)
+        );
+
+        // the bitmap must cover exactly the validator set
+        assert_eq!(
+            SignatureProjective::aggregate_verify_bitmap(
+                &all_pubkeys,
+                &[0b0000_1001],
+                &aggregate_signature,
+                test_message
+            ),
+            Err(BlsError::InputLengthMismatch)
+        );
+        assert_eq!(
+            SignatureProjective::aggregate_verify_bitmap(
+                &all_pubkeys,
+                &[0b0000_1001, 0b0000_0111],
+                &aggregate_signature,
+                test_message
+            ),
+            Err(BlsError::InputLengthMismatch)
+        );
+        assert_eq!(
+            SignatureProjective::aggregate_verify_bitmap(
+                &all_pubkeys,
+                &[0, 0],
+                &aggregate_signature,
+                test_message
+            ),
+            Err(BlsError::EmptyAggregation)
+        );
+    }
+
+    #[test]
+    fn test_signature_inner_point() {
+        let test_message = b"test message";
+        let keypair = Keypair::new();
+        let signature = keypair.sign(test_message);
+        let pubkey = PubkeyProjective::try_from(&keypair.public).unwrap();
+
+        // check e(pk, H(m)) == e(g1, sig) directly with `blstrs`
+        let hashed_message: G2Affine = hash_message_to_point(test_message).into();
+        assert_eq!(
+            blstrs::pairing(&G1Affine::from(pubkey.as_g1()), &hashed_message),
+            blstrs::pairing(
+                &G1Affine::from(blstrs::G1Projective::generator()),
+                &G2Affine::from(signature.as_g2())
+            )
+        );
+        assert_eq!(signature.into_inner(), *signature.as_g2());
+        assert_eq!(pubkey.into_inner(), *pubkey.as_g1());
+    }
+
+    #[test]
+    fn test_aggregator() {
+        let test_message = b"test message";
+        let keypairs: Vec<_> = (0..3).map(|_| Keypair::new()).collect();
+        let signatures: Vec<Signature> = keypairs
+            .iter()
+            .map(|keypair| keypair.sign(test_message).into())
+            .collect();
+
+        let mut aggregator = Aggregator::default();
+        assert_eq!(aggregator.count(), 0);
+        assert_eq!(aggregator.aggregate(), &SignatureProjective::identity());
+        for (i, signature) in signatures.iter().enumerate() {
+            aggregator.push(signature).unwrap();
+            assert_eq!(aggregator.count(), i + 1);
+        }
+
+        // a signature that fails to decode is not counted
+        assert_eq!(
+            aggregator.push(&Signature::default()),
+            Err(BlsError::PointConversion)
+        );
+        assert_eq!(aggregator.count(), signatures.len());
+
+        let (aggregate, count) = aggregator.finish();
+        assert_eq!(count, signatures.len());
+        assert_eq!(
+            aggregate,
+            SignatureProjective::aggregate(signatures.iter()).unwrap()
+        );
+        let aggregate_pubkey =
+            PubkeyProjective::aggregate(keypairs.iter().map(|keypair| &keypair.public)).unwrap();
+        assert!(aggregate_pubkey
+            .verify_signature(&aggregate, test_message)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_aggregate_verify_with_pop() {
+        let test_message = b"test message";
+        let keypairs: Vec<_> = (0..3).map(|_| Keypair::new()).collect();
+        let proofs: Vec<ProofOfPossession> = keypairs
+            .iter()
+            .map(|keypair| keypair.proof_of_possession().into())
+            .collect();
+        let signatures: Vec<Signature> = keypairs
+            .iter()
+            .map(|keypair| keypair.sign(test_message).into())
+            .collect();
+        let entries: Vec<(&Pubkey, &ProofOfPossession, &Signature)> = keypairs
+            .iter()
+            .zip(&proofs)
+            .zip(&signatures)
+            .map(|((keypair, proof), signature)| (&keypair.public, proof, signature))
+            .collect();
+
+        assert_eq!(
+            SignatureProjective::aggregate_verify_with_pop(&entries, test_message),
+            Ok(true)
+        );
+        assert_eq!(
+            SignatureProjective::aggregate_verify_with_pop(&entries, b"other message"),
+            Ok(false)
+        );
+
+        // a rogue key `pk_r - pk_0 - pk_1` lets the attacker alone produce a
+        // signature that verifies as the aggregate of all three signers, but
+        // the attacker cannot prove possession of its secret key
+        let rogue_keypair = Keypair::new();
+        let mut rogue_pubkey = PubkeyProjective::try_from(&rogue_keypair.public).unwrap();
+        rogue_pubkey.0 -= PubkeyProjective::try_from(&keypairs[0].public).unwrap().0;
+        rogue_pubkey.0 -= PubkeyProjective::try_from(&keypairs[1].public).unwrap().0;
+        let rogue_pubkey = Pubkey::from(rogue_pubkey);
+        let rogue_proof: ProofOfPossession = rogue_keypair.proof_of_possession().into();
+        let forged_signature: Signature = rogue_keypair.sign(test_message).into();
+        let identity_signature = Signature::from(SignatureProjective::identity());
+        let forged_entries = [
+            (&keypairs[0].public, &proofs[0], &identity_signature),
+            (&keypairs[1].public, &proofs[1], &identity_signature),
+            (&rogue_pubkey, &rogue_proof, &forged_signature),
+        ];
+        assert_eq!(
+            SignatureProjective::verify_aggregate(
+                forged_entries.iter().map(|(pubkey, _, _)| *pubkey),
+                forged_entries.iter().map(|(_, _, signature)| *signature),
+                test_message
+            ),
+            Ok(true)
+        );
+        assert_eq!(
+            SignatureProjective::aggregate_verify_with_pop(&forged_entries, test_message),
+            Ok(false)
+        );
+
+        assert_eq!(
+            SignatureProjective::aggregate_verify_with_pop(&[], test_message),
+            Err(BlsError::EmptyAggregation)
+        );
+    }
+
+    #[test]
+    fn test_weighted_aggregate_verify() {
+        let test_message = b"test message";
+        let keypairs: Vec<_> = (0..3).map(|_| Keypair::new()).collect();
+        let signatures: Vec<Signature> = keypairs
+            .iter()
+            .map(|keypair| keypair.sign(test_message).into())
+            .collect();
+        let stakes = [100u64, 2_500, 7];
+
+        let weighted_signatures: Vec<(&Signature, u64)> = signatures.iter().zip(stakes).collect();
+        let aggregate_signature =
+            SignatureProjective::weighted_aggregate(&weighted_signatures).unwrap();
+        let weighted_keys: Vec<(&Pubkey, u64)> = keypairs
+            .iter()
+            .map(|keypair| &keypair.public)
+            .zip(stakes)
+            .collect();
+        assert_eq!(
+            SignatureProjective::weighted_aggregate_verify(
+                &weighted_keys,
+                &aggregate_signature,
+                test_message
+            ),
+            Ok(true)
+        );
+
+        // unit weights match the plain aggregate
+        let unit_keys: Vec<(&Pubkey, u64)> = keypairs
+            .iter()
+            .map(|keypair| (&keypair.public, 1))
+            .collect();
+        let plain_aggregate = SignatureProjective::aggregate(signatures.iter()).unwrap();
+        assert_eq!(
+            SignatureProjective::weighted_aggregate_verify(
+                &unit_keys,
+                &plain_aggregate,
+                test_message
+            ),
+            Ok(true)
+        );
+
+        // the weights used for the keys and the signatures must agree
+        assert_eq!(
+            SignatureProjective::weighted_aggregate_verify(
+                &unit_keys,
+                &aggregate_signature,
+                test_message
+            ),
+            Ok(false)
+        );
+        let mut wrong_weights = weighted_keys.clone();
+        wrong_weights[2].1 += 1;
+        assert_eq!(
+            SignatureProjective::weighted_aggregate_verify(
+                &wrong_weights,
+                &aggregate_signature,
+                test_message
+            ),
+            Ok(false)
+        );
+        assert_eq!(
+            SignatureProjective::weighted_aggregate_verify(
+                &weighted_keys,
+                &aggregate_signature,
+                b"other message"
+            ),
+            Ok(false)
+        );
+
+        // a zero-weight signer contributes nothing
+        let mut zero_weight_keys = weighted_keys.clone();
+        zero_weight_keys[1].1 = 0;
+        let zero_weight_signatures = [(&signatures[0], 100), (&signatures[2], 7)];
+        assert_eq!(
+            SignatureProjective::weighted_aggregate_verify(
+                &zero_weight_keys,
+                &SignatureProjective::weighted_aggregate(&zero_weight_signatures).unwrap(),
+                test_message
+            ),
+            Ok(true)
+        );
+
+        let no_weight_keys: Vec<(&Pubkey, u64)> = keypairs
+            .iter()
+            .map(|keypair| (&keypair.public, 0))
+            .collect();
+        assert_eq!(
+            SignatureProjective::weighted_aggregate_verify(
+                &no_weight_keys,
+                &SignatureProjective::identity(),
+                test_message
+            ),
+            Err(BlsError::EmptyAggregation)
+        );
+        assert_eq!(
+            SignatureProjective::weighted_aggregate_verify::<Pubkey, _>(
+                &[],
+                &aggregate_signature,
+                test_message
+            ),
+            Err(BlsError::EmptyAggregation)
+        );
+        assert_eq!(
+            SignatureProjective::weighted_aggregate::<Signature>(&[]),
+            Err(BlsError::EmptyAggregation)
+        );
+    }
+
+    #[test]
+    fn test_verify_groups_short_circuit() {
+        let message0 = b"message zero";
+        let message1 = b"message one";
+        let keypairs: Vec<_> = (0..4).map(|_| Keypair::new()).collect();
+        let pubkeys0 = [keypairs[0].public, keypairs[1].public];
+        let pubkeys1 = [keypairs[2].public, keypairs[3].public];
+        let signatures0 = [keypairs[0].sign(message0), keypairs[1].sign(message0)];
+        let signatures1 = [keypairs[2].sign(message1), keypairs[3].sign(message1)];
+
+        let groups: [(&[Pubkey], &[SignatureProjective], &[u8]); 2] = [
+            (&pubkeys0, &signatures0, message0),
+            (&pubkeys1, &signatures1, message1),
+        ];
+        assert!(SignatureProjective::verify_groups_short_circuit(groups).unwrap());
+
+        // the first group is invalid, so verification stops there
+        let mut processed = 0;
+        let groups: [(&[Pubkey], &[SignatureProjective], &[u8]); 3] = [
+            (&pubkeys0, &signatures0, message1),
+            (&pubkeys1, &signatures1, message1),
+            // an empty group would return an error if it were processed
+            (&[], &[], message1),
+        ];
+        assert!(!SignatureProjective::verify_groups_short_circuit(
+            groups.into_iter().inspect(|_| processed += 1)
+        )
+        .unwrap());
+        assert_eq!(processed, 1);
+
+        // a later invalid group is still detected
+        let groups: [(&[Pubkey], &[SignatureProjective], &[u8]); 2] = [
+            (&pubkeys0, &signatures0, message0),
+            (&pubkeys1, &signatures1, message0),
+        ];
+        assert!(!SignatureProjective::verify_groups_short_circuit(groups).unwrap());
+    }
+
+    #[test]
+    fn test_verify_distinct() {
+        let keypair0 = Keypair::new();
+        let keypair1 = Keypair::new();
+        let keypair2 = Keypair::new();
+
+        let message0 = b"message zero";
+        let message1 = b"message one";
+        let message2 = b"message two";
 
         let signature0_proj = keypair0.sign(message0);
         let signature1_proj = keypair1.sign(message1);
@@ -657,6 +1811,148 @@ mod tests {
         assert_eq!(err, BlsError::EmptyAggregation);
     }
 
+    #[test]
+    fn test_aggregate_verify_distinct() {
+        let keypairs: Vec<_> = (0..3).map(|_| Keypair::new()).collect();
+        let messages: [&[u8]; 3] = [b"message zero", b"message one", b"message two"];
+        let aggregate_signature = SignatureProjective::aggregate(
+            keypairs
+                .iter()
+                .zip(messages)
+                .map(|(keypair, message)| keypair.sign(message))
+                .collect::<Vec<_>>()
+                .iter(),
+        )
+        .unwrap();
+
+        // public keys and signatures in any representation
+        let pubkeys: Vec<&Pubkey> = keypairs.iter().map(|keypair| &keypair.public).collect();
+        let pubkeys_projective: Vec<PubkeyProjective> = keypairs
+            .iter()
+            .map(|keypair| (&keypair.public).try_into().unwrap())
+            .collect();
+        let pubkeys_projective: Vec<&PubkeyProjective> = pubkeys_projective.iter().collect();
+        let aggregate_signature_affine: Signature = aggregate_signature.into();
+        let aggregate_signature_compressed: SignatureCompressed =
+            aggregate_signature_affine.try_into().unwrap();
+        assert_eq!(
+            SignatureProjective::aggregate_verify_distinct(
+                &pubkeys,
+                &messages,
+                &aggregate_signature
+            ),
+            Ok(true)
+        );
+        assert_eq!(
+            SignatureProjective::aggregate_verify_distinct(
+                &pubkeys_projective,
+                &messages,
+                &aggregate_signature_compressed
+            ),
+            Ok(true)
+        );
+        // matches the non-generic variant
+        assert_eq!(
+            SignatureProjective::verify_distinct_aggregated(
+                pubkeys.iter().copied(),
+                &aggregate_signature_affine,
+                messages.iter().copied()
+            ),
+            Ok(true)
+        );
+
+        // each public key must be paired with its own message
+        let swapped_messages = [messages[1], messages[0], messages[2]];
+        assert_eq!(
+            SignatureProjective::aggregate_verify_distinct(
+                &pubkeys,
+                &swapped_messages,
+                &aggregate_signature
+            ),
+            Ok(false)
+        );
+
+        assert_eq!(
+            SignatureProjective::aggregate_verify_distinct(
+                &pubkeys,
+                &messages[..2],
+                &aggregate_signature
+            ),
+            Err(BlsError::InputLengthMismatch)
+        );
+        assert_eq!(
+            SignatureProjective::aggregate_verify_distinct::<Pubkey, _>(
+                &[],
+                &[],
+                &aggregate_signature
+            ),
+            Err(BlsError::EmptyAggregation)
+        );
+    }
+
+    #[test]
+    fn test_batch_verify() {
+        let keypairs: Vec<_> = (0..4).map(|_| Keypair::new()).collect();
+        let messages: Vec<Vec<u8>> = (0..4u8).map(|i| std::vec![i; 8]).collect();
+        let signatures: Vec<Signature> = keypairs
+            .iter()
+            .zip(&messages)
+            .map(|(keypair, message)| keypair.sign(message).into())
+            .collect();
+        let items: Vec<(&Pubkey, &[u8], &Signature)> = keypairs
+            .iter()
+            .zip(&messages)
+            .zip(&signatures)
+            .map(|((keypair, message), signature)| (&keypair.public, message.as_slice(), signature))
+            .collect();
+        assert_eq!(SignatureProjective::batch_verify(&items), Ok(true));
+        assert_eq!(SignatureProjective::batch_verify(&items[..1]), Ok(true));
+
+        // the same message may appear in several triples
+        let signature = keypairs[1].sign(&messages[0]);
+        let compressed: SignatureCompressed = Signature::from(signature).try_into().unwrap();
+        assert_eq!(
+            SignatureProjective::batch_verify(&[
+                (&keypairs[0].public, messages[0].as_slice(), &compressed),
+                (&keypairs[1].public, messages[0].as_slice(), &compressed),
+            ]),
+            Ok(false)
+        );
+        assert_eq!(
+            SignatureProjective::batch_verify(&[(
+                &keypairs[1].public,
+                messages[0].as_slice(),
+                &compressed
+            )]),
+            Ok(true)
+        );
+
+        // one invalid signature fails the whole batch
+        let mut wrong_message = items.clone();
+        wrong_message[2].1 = b"wrong message";
+        assert_eq!(SignatureProjective::batch_verify(&wrong_message), Ok(false));
+
+        // swapping two signatures keeps the plain aggregate unchanged, but
+        // the random weights still catch it
+        let mut swapped = items.clone();
+        swapped[0].2 = &signatures[1];
+        swapped[1].2 = &signatures[0];
+        assert_eq!(SignatureProjective::batch_verify(&swapped), Ok(false));
+
+        let malformed = Signature([0xff; BLS_SIGNATURE_AFFINE_SIZE]);
+        let mut malformed_items = items.clone();
+        malformed_items[3].2 = &malformed;
+        assert_eq!(
+            SignatureProjective::batch_verify(&malformed_items),
+            Err(BlsError::PointConversion)
+        );
+
+        assert_eq!(
+            SignatureProjective::batch_verify::<Pubkey, Signature>(&[]),
+            Err(BlsError::EmptyAggregation)
+        );
+    }
+
     #[test]
     fn test_verify_aggregate_dyn() {
         let test_message = b"test message for dyn verify";
@@ -717,6 +2013,77 @@ mod tests {
         assert_eq!(signature_compressed, signature_compressed_from_string);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_and_deserialize_signature_projective() {
+        let keypair = Keypair::new();
+        let original = keypair.sign(b"test message");
+        let serialized = bincode::serialize(&original).unwrap();
+        let compressed: SignatureCompressed = Signature::from(original).try_into().unwrap();
+        assert_eq!(serialized, bincode::serialize(&compressed).unwrap());
+        let deserialized: SignatureProjective = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(original, deserialized);
+
+        let aggregate =
+            SignatureProjective::aggregate([&original, &keypair.sign(b"other")].into_iter())
+                .unwrap();
+        let deserialized: SignatureProjective =
+            bincode::deserialize(&bincode::serialize(&aggregate).unwrap()).unwrap();
+        assert_eq!(aggregate, deserialized);
+
+        // bytes that are not a valid compressed point are rejected
+        let malformed = SignatureCompressed([0xff; BLS_SIGNATURE_COMPRESSED_SIZE]);
+        assert!(bincode::deserialize::<SignatureProjective>(
+            &bincode::serialize(&malformed).unwrap()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn signature_hex() {
+        let keypair = Keypair::new();
+        let signature_affine: Signature = keypair.sign(b"test message").into();
+        let hex = signature_affine.to_hex();
+        assert_eq!(hex.len(), BLS_SIGNATURE_AFFINE_SIZE * 2);
+        assert_eq!(Signature::from_hex(&hex), Ok(signature_affine));
+        assert_eq!(
+            Signature::from_hex(&std::format!("0x{hex}")),
+            Ok(signature_affine)
+        );
+        assert_eq!(
+            Signature::from_hex(&hex.to_uppercase()),
+            Ok(signature_affine)
+        );
+
+        let signature_compressed = SignatureCompressed([0xab; BLS_SIGNATURE_COMPRESSED_SIZE]);
+        assert_eq!(
+            signature_compressed.to_hex(),
+            "ab".repeat(BLS_SIGNATURE_COMPRESSED_SIZE)
+        );
+        assert_eq!(
+            SignatureCompressed::from_hex(&signature_compressed.to_hex()),
+            Ok(signature_compressed)
+        );
+
+        // wrong length, non-hex characters and base64 input are rejected
+        assert_eq!(
+            SignatureCompressed::from_hex(&hex),
+            Err(BlsError::ParseFromString)
+        );
+        assert_eq!(
+            Signature::from_hex(&hex[2..]),
+            Err(BlsError::ParseFromString)
+        );
+        assert_eq!(
+            Signature::from_hex(&std::format!("+f{}", &hex[2..])),
+            Err(BlsError::ParseFromString)
+        );
+        assert_eq!(
+            Signature::from_hex(&signature_affine.to_string()),
+            Err(BlsError::ParseFromString)
+        );
+    }
+
     #[test]
     #[cfg(feature = "parallel")]
     fn test_parallel_signature_aggregation() {
@@ -805,4 +2172,200 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_par_aggregate_verify_distinct() {
+        let keypairs: Vec<_> = (0..8).map(|_| Keypair::new()).collect();
+        let messages: Vec<Vec<u8>> = (0..8u8).map(|i| std::vec![i; 8]).collect();
+        let message_refs: Vec<&[u8]> = messages.iter().map(Vec::as_slice).collect();
+        let signatures: Vec<Signature> = keypairs
+            .iter()
+            .zip(&messages)
+            .map(|(keypair, message)| keypair.sign(message).into())
+            .collect();
+        let pubkeys: Vec<&Pubkey> = keypairs.iter().map(|keypair| &keypair.public).collect();
+        let mut signature_refs: Vec<&Signature> = signatures.iter().collect();
+        assert_eq!(
+            SignatureProjective::par_aggregate_verify_distinct(
+                &pubkeys,
+                &message_refs,
+                &signature_refs
+            ),
+            Ok(true)
+        );
+        // matches the sequential variant over the aggregate
+        assert_eq!(
+            SignatureProjective::aggregate_verify_distinct(
+                &pubkeys,
+                &message_refs,
+                &SignatureProjective::aggregate(signatures.iter()).unwrap()
+            ),
+            Ok(true)
+        );
+
+        signature_refs.swap(0, 1);
+        assert_eq!(
+            SignatureProjective::par_aggregate_verify_distinct(
+                &pubkeys,
+                &message_refs,
+                &signature_refs
+            ),
+            Ok(true),
+            "aggregation is order-independent"
+        );
+        let mut wrong_messages = message_refs.clone();
+        wrong_messages.swap(0, 1);
+        assert_eq!(
+            SignatureProjective::par_aggregate_verify_distinct(
+                &pubkeys,
+                &wrong_messages,
+                &signature_refs
+            ),
+            Ok(false)
+        );
+
+        assert_eq!(
+            SignatureProjective::par_aggregate_verify_distinct(
+                &pubkeys,
+                &message_refs,
+                &signature_refs[1..]
+            ),
+            Err(BlsError::InputLengthMismatch)
+        );
+        assert_eq!(
+            SignatureProjective::par_aggregate_verify_distinct::<Pubkey, Signature>(&[], &[], &[]),
+            Err(BlsError::EmptyAggregation)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_par_batch_verify() {
+        let keypairs: Vec<_> = (0..8).map(|_| Keypair::new()).collect();
+        let messages: Vec<Vec<u8>> = (0..8u8).map(|i| std::vec![i; 8]).collect();
+        let signatures: Vec<SignatureProjective> = keypairs
+            .iter()
+            .zip(&messages)
+            .map(|(keypair, message)| keypair.sign(message))
+            .collect();
+        let mut items: Vec<(&Pubkey, &[u8], &SignatureProjective)> = keypairs
+            .iter()
+            .zip(&messages)
+            .zip(&signatures)
+            .map(|((keypair, message), signature)| (&keypair.public, message.as_slice(), signature))
+            .collect();
+        assert_eq!(SignatureProjective::par_batch_verify(&items), Ok(true));
+
+        items[5].1 = b"wrong message";
+        assert_eq!(SignatureProjective::par_batch_verify(&items), Ok(false));
+        assert_eq!(
+            SignatureProjective::par_batch_verify::<Pubkey, Signature>(&[]),
+            Err(BlsError::EmptyAggregation)
+        );
+    }
+}
+
+/// Signing and verification against fixed vectors, so that both are also
+/// covered in builds without the `rand` feature
+#[cfg(test)]
+mod vector_tests {
+    use {
+        super::*,
+        crate::{
+            keypair::Keypair,
+            pubkey::{PubkeyCompressed, VerifiablePubkey},
+        },
+        core::str::FromStr,
+    };
+
+    // Signatures over `VECTOR_MESSAGE` by the keys derived from the IKMs
+    // `[1; 32]` and `[2; 32]`
+    const VECTOR_MESSAGE: &[u8] = b"light client";
+    const VECTOR_PUBKEYS: [&str; 2] = [
+        "laJUUBt3MyOe087E1Wc3l3vQnt6IHYojRWDoPlUlAXrdOx3MPqv7heEqQTGxnCU7",
+        "rICl4IxxLV8I8DBq10P32MIV2YJIm4Sh1rqAVzPZTABuiTj5CJp12z/6E1rzO8aa",
+    ];
+    const VECTOR_SIGNATURES: [&str; 2] = [
+        "jNdKPj+/9wLpCswkdAAvyROWe0ZtQEGLqw2vVe5VcN5fV7BU6jaYmPFzTGhXyNNFBedm6BhXBaqZ/aUVZgjBFNLfce8AdEE6jpW51ZsBQr8p9gM0ClRL5f4o0jfJXas9",
+        "qJ6/iLaQHsepQMKykOdeWSh1sNwPops158JURTzzgfkWyxLiFGsD/muEqBjCF9TKANjPPDzYevipYeEQocLQ9Mn4+Mt3PzahRI6Ap3M4W9FOTRzKB56jadhAbKRxJjZx",
+    ];
+
+    fn vectors() -> ([PubkeyCompressed; 2], [SignatureCompressed; 2]) {
+        (
+            VECTOR_PUBKEYS.map(|pubkey| PubkeyCompressed::from_str(pubkey).unwrap()),
+            VECTOR_SIGNATURES.map(|signature| SignatureCompressed::from_str(signature).unwrap()),
+        )
+    }
+
+    #[test]
+    fn test_verify_fixed_vectors() {
+        let (pubkeys, signatures) = vectors();
+
+        assert_eq!(
+            pubkeys[0].verify_signature(&signatures[0], VECTOR_MESSAGE),
+            Ok(true)
+        );
+        assert_eq!(
+            pubkeys[1].verify_signature(&signatures[1], VECTOR_MESSAGE),
+            Ok(true)
+        );
+        assert_eq!(
+            pubkeys[0].verify_signature(&signatures[1], VECTOR_MESSAGE),
+            Ok(false)
+        );
+        assert_eq!(
+            pubkeys[0].verify_signature(&signatures[0], b"other message"),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn test_sign_fixed_vectors() {
+        let (pubkeys, signatures) = vectors();
+
+        for (i, (pubkey, signature)) in pubkeys.iter().zip(signatures.iter()).enumerate() {
+            let ikm = [u8::try_from(i).unwrap().saturating_add(1); 32];
+            let keypair = Keypair::derive(&ikm).unwrap();
+            assert_eq!(
+                PubkeyCompressed::try_from(keypair.public).as_ref(),
+                Ok(pubkey)
+            );
+            let signed: Signature = keypair.sign(VECTOR_MESSAGE).into();
+            assert_eq!(
+                SignatureCompressed::try_from(signed).as_ref(),
+                Ok(signature)
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_aggregate_fixed_vectors() {
+        let (pubkeys, signatures) = vectors();
+
+        assert_eq!(
+            SignatureProjective::verify_aggregate(
+                pubkeys.iter(),
+                signatures.iter(),
+                VECTOR_MESSAGE
+            ),
+            Ok(true)
+        );
+        assert_eq!(
+            SignatureProjective::verify_aggregate(
+                pubkeys.iter(),
+                signatures.iter(),
+                b"other message"
+            ),
+            Ok(false)
+        );
+        assert_eq!(
+            SignatureProjective::verify_aggregate(
+                pubkeys[..1].iter(),
+                signatures.iter(),
+                VECTOR_MESSAGE
+            ),
+            Ok(false)
+        );
+    }
 }
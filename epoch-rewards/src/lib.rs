@@ -17,7 +17,28 @@ pub mod sysvar;
 extern crate std;
 #[cfg(feature = "serde")]
 use serde_derive::{Deserialize, Serialize};
-use {solana_hash::Hash, solana_sdk_macro::CloneZeroed};
+use {solana_hash::Hash, solana_program_error::ProgramError, solana_sdk_macro::CloneZeroed};
+
+/// Errors returned by the [`EpochRewards`] rewards-period guards.
+///
+/// Each variant converts into [`ProgramError::Custom`] with the variant's
+/// discriminant as the error code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum EpochRewardsError {
+    /// The operation requires the rewards period to be inactive, but it is
+    /// active. Custom error code `0`.
+    RewardsPeriodActive = 0,
+    /// The operation requires the rewards period to be active, but it is
+    /// inactive. Custom error code `1`.
+    RewardsPeriodInactive = 1,
+}
+
+impl From<EpochRewardsError> for ProgramError {
+    fn from(error: EpochRewardsError) -> Self {
+        ProgramError::Custom(error as u32)
+    }
+}
 
 #[repr(C, align(16))]
 #[cfg_attr(feature = "frozen-abi", derive(solana_frozen_abi_macro::AbiExample))]
@@ -60,6 +81,28 @@ impl EpochRewards {
         assert!(new_distributed_rewards <= self.total_rewards);
         self.distributed_rewards = new_distributed_rewards;
     }
+
+    /// Returns an error unless the rewards period is active.
+    ///
+    /// Fails with [`EpochRewardsError::RewardsPeriodInactive`].
+    pub fn require_active(&self) -> Result<(), ProgramError> {
+        if self.active {
+            Ok(())
+        } else {
+            Err(EpochRewardsError::RewardsPeriodInactive.into())
+        }
+    }
+
+    /// Returns an error if the rewards period is active.
+    ///
+    /// Fails with [`EpochRewardsError::RewardsPeriodActive`].
+    pub fn require_inactive(&self) -> Result<(), ProgramError> {
+        if self.active {
+            Err(EpochRewardsError::RewardsPeriodActive.into())
+        } else {
+            Ok(())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -105,4 +148,18 @@ mod tests {
         let mut epoch_rewards = EpochRewards::new(100, 0, 64);
         epoch_rewards.distribute(200);
     }
+
+    #[test]
+    fn test_epoch_rewards_require_active() {
+        let mut epoch_rewards = EpochRewards::new(100, 0, 64);
+        assert_eq!(epoch_rewards.require_active(), Err(ProgramError::Custom(1)));
+        assert_eq!(epoch_rewards.require_inactive(), Ok(()));
+
+        epoch_rewards.active = true;
+        assert_eq!(epoch_rewards.require_active(), Ok(()));
+        assert_eq!(
+            epoch_rewards.require_inactive(),
+            Err(ProgramError::Custom(0))
+        );
+    }
 }
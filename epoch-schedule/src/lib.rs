@@ -205,6 +205,20 @@ impl EpochSchedule {
             .saturating_add(self.get_slots_in_epoch(epoch))
             .saturating_sub(1)
     }
+
+    /// Returns the first and last slot spanned by `epochs`, or `None` if
+    /// `epochs` is empty.
+    ///
+    /// The epochs need not be sorted or contiguous; the span runs from the
+    /// first slot of the lowest epoch to the last slot of the highest.
+    pub fn slot_bounds(&self, epochs: &[u64]) -> Option<(u64, u64)> {
+        let min_epoch = epochs.iter().min()?;
+        let max_epoch = epochs.iter().max()?;
+        Some((
+            self.get_first_slot_in_epoch(*min_epoch),
+            self.get_last_slot_in_epoch(*max_epoch),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -269,6 +283,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_slot_bounds() {
+        let epoch_schedule = EpochSchedule::custom(MINIMUM_SLOTS_PER_EPOCH * 16, 0, true);
+        assert_eq!(epoch_schedule.slot_bounds(&[]), None);
+        assert_eq!(
+            epoch_schedule.slot_bounds(&[2, 5, 3]),
+            Some((
+                epoch_schedule.get_first_slot_in_epoch(2),
+                epoch_schedule.get_last_slot_in_epoch(5),
+            ))
+        );
+        // epoch 2 is still in warmup, epoch 5 is past the first normal epoch
+        assert_eq!(
+            epoch_schedule.slot_bounds(&[2, 5, 3]),
+            Some((
+                3 * MINIMUM_SLOTS_PER_EPOCH,
+                47 * MINIMUM_SLOTS_PER_EPOCH - 1
+            ))
+        );
+        assert_eq!(
+            epoch_schedule.slot_bounds(&[4]),
+            Some((
+                epoch_schedule.get_first_slot_in_epoch(4),
+                epoch_schedule.get_last_slot_in_epoch(4),
+            ))
+        );
+    }
+
     #[test]
     fn test_clone() {
         let epoch_schedule = EpochSchedule {
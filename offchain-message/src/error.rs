@@ -0,0 +1,67 @@
+use {core::fmt, solana_sanitize::SanitizeError};
+
+/// Errors produced when constructing or deserializing an off-chain message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffchainMessageError {
+    /// The message body is empty
+    EmptyMessage,
+    /// The message body exceeds the maximum length for its version
+    MessageTooLarge,
+    /// The serialized data is too short to contain a header and a body
+    TooShort,
+    /// The header version is not supported
+    UnsupportedVersion,
+    /// The message format byte is unknown, or the body violates the
+    /// constraints of its declared format
+    InvalidFormat,
+    /// The message body is not valid UTF-8
+    NonUtf8,
+    /// The length declared in the header does not match the body length
+    LengthMismatch,
+    /// The data does not start with the detached signature file magic bytes
+    InvalidMagic,
+    /// The data does not start with the off-chain message signing domain
+    InvalidSigningDomain,
+    /// More signatures were given than the format can hold
+    TooManySigners,
+}
+
+impl core::error::Error for OffchainMessageError {}
+
+impl fmt::Display for OffchainMessageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OffchainMessageError::EmptyMessage => f.write_str("message body is empty"),
+            OffchainMessageError::MessageTooLarge => f.write_str("message body is too large"),
+            OffchainMessageError::TooShort => f.write_str("serialized message is too short"),
+            OffchainMessageError::UnsupportedVersion => {
+                f.write_str("unsupported message header version")
+            }
+            OffchainMessageError::InvalidFormat => f.write_str("invalid message format"),
+            OffchainMessageError::NonUtf8 => f.write_str("message body is not valid UTF-8"),
+            OffchainMessageError::LengthMismatch => {
+                f.write_str("message length does not match header")
+            }
+            OffchainMessageError::InvalidMagic => f.write_str("invalid signed file magic bytes"),
+            OffchainMessageError::InvalidSigningDomain => f.write_str("invalid signing domain"),
+            OffchainMessageError::TooManySigners => f.write_str("too many signers"),
+        }
+    }
+}
+
+impl From<OffchainMessageError> for SanitizeError {
+    fn from(error: OffchainMessageError) -> Self {
+        match error {
+            OffchainMessageError::MessageTooLarge
+            | OffchainMessageError::TooShort
+            | OffchainMessageError::UnsupportedVersion
+            | OffchainMessageError::TooManySigners => SanitizeError::ValueOutOfBounds,
+            OffchainMessageError::EmptyMessage
+            | OffchainMessageError::InvalidFormat
+            | OffchainMessageError::NonUtf8
+            | OffchainMessageError::LengthMismatch
+            | OffchainMessageError::InvalidMagic
+            | OffchainMessageError::InvalidSigningDomain => SanitizeError::InvalidValue,
+        }
+    }
+}
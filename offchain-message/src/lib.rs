@@ -1,12 +1,30 @@
 //! Off-chain message container for storing non-transaction messages.
+#![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 use {
+    crate::error::OffchainMessageError,
+    alloc::vec::Vec,
+    core::{fmt, str::FromStr},
     num_enum::{IntoPrimitive, TryFromPrimitive},
     solana_hash::Hash,
     solana_sanitize::SanitizeError,
-    solana_signature::Signature,
-    solana_signer::Signer,
+    solana_signature::{Signature, SIGNATURE_BYTES},
 };
+#[cfg(feature = "std")]
+use {solana_signer::Signer, std::io::Write};
+
+pub mod error;
+
+/// Maximum over-the-wire size of a transaction, as in `solana_packet`
+const PACKET_DATA_SIZE: usize = 1280 - 40 - 8;
+
+#[cfg(test)]
+static_assertions::const_assert_eq!(PACKET_DATA_SIZE, solana_packet::PACKET_DATA_SIZE);
 
 #[cfg(test)]
 static_assertions::const_assert_eq!(OffchainMessage::HEADER_LEN, 17);
@@ -27,30 +45,49 @@ pub fn is_printable_ascii(data: &[u8]) -> bool {
 
 /// Check if given bytes contain valid UTF8 string
 pub fn is_utf8(data: &[u8]) -> bool {
-    std::str::from_utf8(data).is_ok()
+    core::str::from_utf8(data).is_ok()
 }
 
 #[repr(u8)]
-#[derive(Debug, PartialEq, Eq, Copy, Clone, TryFromPrimitive, IntoPrimitive)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, TryFromPrimitive, IntoPrimitive)]
 pub enum MessageFormat {
     RestrictedAscii,
     LimitedUtf8,
     ExtendedUtf8,
 }
 
+/// Why [`v0::OffchainMessage::detect_format_explained`] selected a
+/// [`MessageFormat`]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum FormatReason {
+    /// The message is printable ASCII and fits the Ledger limit
+    AsciiUnderLimit,
+    /// The message is UTF-8 but not printable ASCII, and fits the Ledger limit
+    Utf8UnderLimit,
+    /// The message is UTF-8 but longer than the Ledger limit, so it cannot be
+    /// signed on a hardware wallet
+    ExceededLedgerLimit,
+}
+
 #[allow(clippy::arithmetic_side_effects)]
 pub mod v0 {
+    #[cfg(feature = "std")]
+    use std::io::Write;
     use {
-        super::{is_printable_ascii, is_utf8, MessageFormat, OffchainMessage as Base},
+        super::PACKET_DATA_SIZE,
+        super::{
+            is_printable_ascii, is_utf8, FormatReason, MessageFormat, OffchainMessage as Base,
+        },
+        crate::error::OffchainMessageError,
+        alloc::vec::Vec,
         solana_hash::Hash,
-        solana_packet::PACKET_DATA_SIZE,
         solana_sanitize::SanitizeError,
         solana_sha256_hasher::Hasher,
     };
 
     /// OffchainMessage Version 0.
     /// Struct always contains a non-empty valid message.
-    #[derive(Debug, PartialEq, Eq, Clone)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
     pub struct OffchainMessage {
         format: MessageFormat,
         message: Vec<u8>,
@@ -65,79 +102,174 @@ pub mod v0 {
         pub const MAX_LEN_LEDGER: usize = PACKET_DATA_SIZE - Base::HEADER_LEN - Self::HEADER_LEN;
 
         /// Construct a new OffchainMessage object from the given message
-        pub fn new(message: &[u8]) -> Result<Self, SanitizeError> {
-            let format = if message.is_empty() {
-                return Err(SanitizeError::InvalidValue);
+        pub fn new(message: &[u8]) -> Result<Self, OffchainMessageError> {
+            Ok(Self {
+                format: Self::minimal_format(message)?,
+                message: message.to_vec(),
+            })
+        }
+
+        /// Construct a new OffchainMessage object with the given format,
+        /// failing if the message does not satisfy that format's constraints
+        pub fn new_with_format(
+            format: MessageFormat,
+            message: &[u8],
+        ) -> Result<Self, OffchainMessageError> {
+            if message.is_empty() {
+                return Err(OffchainMessageError::EmptyMessage);
+            }
+            if message.len() > Self::MAX_LEN {
+                return Err(OffchainMessageError::MessageTooLarge);
+            }
+            if Self::format_violation(format, message).is_some() {
+                return Err(OffchainMessageError::InvalidFormat);
+            }
+            Ok(Self {
+                format,
+                message: message.to_vec(),
+            })
+        }
+
+        /// Check the length and content constraints of `format` against
+        /// `message`, returning the index of the first byte that violates them
+        fn format_violation(format: MessageFormat, message: &[u8]) -> Option<usize> {
+            let utf8_violation =
+                |message: &[u8]| core::str::from_utf8(message).err().map(|e| e.valid_up_to());
+            let (max_len, content_violation) = match format {
+                MessageFormat::RestrictedAscii => (
+                    Self::MAX_LEN_LEDGER,
+                    message
+                        .iter()
+                        .position(|char| !(0x20..=0x7e).contains(char)),
+                ),
+                MessageFormat::LimitedUtf8 => (Self::MAX_LEN_LEDGER, utf8_violation(message)),
+                MessageFormat::ExtendedUtf8 => (Self::MAX_LEN, utf8_violation(message)),
+            };
+            let length_violation = (message.len() > max_len).then_some(max_len);
+            content_violation.into_iter().chain(length_violation).min()
+        }
+
+        /// Return the most restrictive message format the given body
+        /// satisfies, which is the format [`OffchainMessage::new`] selects
+        ///
+        /// Empty, non-UTF-8 and oversized bodies are rejected with the same
+        /// errors as [`OffchainMessage::new`].
+        pub fn minimal_format(message: &[u8]) -> Result<MessageFormat, OffchainMessageError> {
+            Self::detect_format_explained(message).map(|(format, _)| format)
+        }
+
+        /// Select the message format for the given message body, along with
+        /// the reason it was selected
+        ///
+        /// This is the selection made by [`OffchainMessage::new`]. The reason
+        /// lets callers explain, for instance, that a message got
+        /// [`MessageFormat::ExtendedUtf8`] because it is longer than
+        /// [`OffchainMessage::MAX_LEN_LEDGER`] and so cannot be signed on a
+        /// Ledger.
+        pub fn detect_format_explained(
+            message: &[u8],
+        ) -> Result<(MessageFormat, FormatReason), OffchainMessageError> {
+            if message.is_empty() {
+                Err(OffchainMessageError::EmptyMessage)
             } else if message.len() <= OffchainMessage::MAX_LEN_LEDGER {
                 if is_printable_ascii(message) {
-                    MessageFormat::RestrictedAscii
+                    Ok((
+                        MessageFormat::RestrictedAscii,
+                        FormatReason::AsciiUnderLimit,
+                    ))
                 } else if is_utf8(message) {
-                    MessageFormat::LimitedUtf8
+                    Ok((MessageFormat::LimitedUtf8, FormatReason::Utf8UnderLimit))
                 } else {
-                    return Err(SanitizeError::InvalidValue);
+                    Err(OffchainMessageError::NonUtf8)
                 }
             } else if message.len() <= OffchainMessage::MAX_LEN {
                 if is_utf8(message) {
-                    MessageFormat::ExtendedUtf8
+                    Ok((
+                        MessageFormat::ExtendedUtf8,
+                        FormatReason::ExceededLedgerLimit,
+                    ))
                 } else {
-                    return Err(SanitizeError::InvalidValue);
+                    Err(OffchainMessageError::NonUtf8)
                 }
             } else {
-                return Err(SanitizeError::ValueOutOfBounds);
-            };
-            Ok(Self {
-                format,
-                message: message.to_vec(),
-            })
+                Err(OffchainMessageError::MessageTooLarge)
+            }
         }
 
         /// Serialize the message to bytes, including the full header
         pub fn serialize(&self, data: &mut Vec<u8>) -> Result<(), SanitizeError> {
             // invalid messages shouldn't be possible, but a quick sanity check never hurts
             assert!(!self.message.is_empty() && self.message.len() <= Self::MAX_LEN);
-            data.reserve(Self::HEADER_LEN.saturating_add(self.message.len()));
-            // format
-            data.push(self.format.into());
-            // message length
-            data.extend_from_slice(&(self.message.len() as u16).to_le_bytes());
-            // message
-            data.extend_from_slice(&self.message);
+            Self::serialize_parts(self.format, &self.message, data);
             Ok(())
         }
 
+        /// Serialize the message, including the full header, into `writer`
+        #[cfg(feature = "std")]
+        pub fn serialize_into<W: Write>(&self, writer: &mut W) -> Result<(), SanitizeError> {
+            // invalid messages shouldn't be possible, but a quick sanity check never hurts
+            assert!(!self.message.is_empty() && self.message.len() <= Self::MAX_LEN);
+            writer
+                .write_all(&Self::header(self.format, self.message.len()))
+                .and_then(|()| writer.write_all(&self.message))
+                .map_err(|_| SanitizeError::InvalidValue)
+        }
+
+        /// Append the header and the message body to `data`
+        pub(crate) fn serialize_parts(format: MessageFormat, message: &[u8], data: &mut Vec<u8>) {
+            data.reserve(Self::HEADER_LEN.saturating_add(message.len()));
+            data.extend_from_slice(&Self::header(format, message.len()));
+            data.extend_from_slice(message);
+        }
+
+        /// Encode the format and the message length
+        fn header(format: MessageFormat, message_len: usize) -> [u8; Self::HEADER_LEN] {
+            let [len_lo, len_hi] = (message_len as u16).to_le_bytes();
+            [format.into(), len_lo, len_hi]
+        }
+
         /// Deserialize the message from bytes that include a full header
-        pub fn deserialize(data: &[u8]) -> Result<Self, SanitizeError> {
+        pub fn deserialize(data: &[u8]) -> Result<Self, OffchainMessageError> {
+            Self::deserialize_verbose(data).map_err(|(err, _)| err)
+        }
+
+        /// Deserialize the message like [`OffchainMessage::deserialize`],
+        /// also reporting the offset into `data` at which parsing failed
+        ///
+        /// The offset is that of the header field that was rejected, or of
+        /// the first body byte that violates the declared format. For data
+        /// that is too short or too long, it is where the data ended or
+        /// should have ended.
+        pub fn deserialize_verbose(data: &[u8]) -> Result<Self, (OffchainMessageError, usize)> {
             // validate data length
-            if data.len() <= Self::HEADER_LEN || data.len() > Self::HEADER_LEN + Self::MAX_LEN {
-                return Err(SanitizeError::ValueOutOfBounds);
+            if data.len() <= Self::HEADER_LEN {
+                return Err((OffchainMessageError::TooShort, data.len()));
+            }
+            if data.len() > Self::HEADER_LEN + Self::MAX_LEN {
+                return Err((
+                    OffchainMessageError::MessageTooLarge,
+                    Self::HEADER_LEN + Self::MAX_LEN,
+                ));
             }
             // decode header
-            let format =
-                MessageFormat::try_from(data[0]).map_err(|_| SanitizeError::InvalidValue)?;
+            let format = MessageFormat::try_from(data[0])
+                .map_err(|_| (OffchainMessageError::InvalidFormat, 0))?;
             let message_len = u16::from_le_bytes([data[1], data[2]]) as usize;
             // check header
             if Self::HEADER_LEN.saturating_add(message_len) != data.len() {
-                return Err(SanitizeError::InvalidValue);
+                return Err((OffchainMessageError::LengthMismatch, 1));
             }
             let message = &data[Self::HEADER_LEN..];
             // check format
-            let is_valid = match format {
-                MessageFormat::RestrictedAscii => {
-                    (message.len() <= Self::MAX_LEN_LEDGER) && is_printable_ascii(message)
-                }
-                MessageFormat::LimitedUtf8 => {
-                    (message.len() <= Self::MAX_LEN_LEDGER) && is_utf8(message)
-                }
-                MessageFormat::ExtendedUtf8 => (message.len() <= Self::MAX_LEN) && is_utf8(message),
-            };
-
-            if is_valid {
-                Ok(Self {
+            match Self::format_violation(format, message) {
+                None => Ok(Self {
                     format,
                     message: message.to_vec(),
-                })
-            } else {
-                Err(SanitizeError::InvalidValue)
+                }),
+                Some(index) => Err((
+                    OffchainMessageError::InvalidFormat,
+                    Self::HEADER_LEN + index,
+                )),
             }
         }
 
@@ -158,7 +290,13 @@ pub mod v0 {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+/// An off-chain message of any supported version.
+///
+/// New versions may be added, so matches on this enum outside this crate need
+/// a wildcard arm. Prefer the version-agnostic accessors such as
+/// [`OffchainMessage::get_format`] and [`OffchainMessage::body`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[non_exhaustive]
 pub enum OffchainMessage {
     V0(v0::OffchainMessage),
 }
@@ -167,40 +305,171 @@ impl OffchainMessage {
     pub const SIGNING_DOMAIN: &'static [u8] = b"\xffsolana offchain";
     // Header Length = Signing Domain (16) + Header Version (1)
     pub const HEADER_LEN: usize = Self::SIGNING_DOMAIN.len() + 1;
+    /// Magic bytes at the start of a detached signature file
+    pub const SIGNED_FILE_MAGIC: [u8; 8] = *b"SOLSIGN\0";
+    /// Current version of the detached signature file layout
+    pub const SIGNED_FILE_VERSION: u8 = 0;
+    // Signed File Header Length = Magic (8) + File Version (1) + Signature Count (1)
+    const SIGNED_FILE_HEADER_LEN: usize = Self::SIGNED_FILE_MAGIC.len() + 2;
 
     /// Construct a new OffchainMessage object from the given version and message
-    pub fn new(version: u8, message: &[u8]) -> Result<Self, SanitizeError> {
+    pub fn new(version: u8, message: &[u8]) -> Result<Self, OffchainMessageError> {
         match version {
             0 => Ok(Self::V0(v0::OffchainMessage::new(message)?)),
-            _ => Err(SanitizeError::ValueOutOfBounds),
+            _ => Err(OffchainMessageError::UnsupportedVersion),
+        }
+    }
+
+    /// Construct a new OffchainMessage object from the given version, format
+    /// and message.
+    ///
+    /// Unlike [`OffchainMessage::new`], the format is not detected from the
+    /// message but stored as given, after checking that the message satisfies
+    /// its constraints.
+    pub fn new_with_format(
+        version: u8,
+        format: MessageFormat,
+        message: &[u8],
+    ) -> Result<Self, OffchainMessageError> {
+        match version {
+            0 => Ok(Self::V0(v0::OffchainMessage::new_with_format(
+                format, message,
+            )?)),
+            _ => Err(OffchainMessageError::UnsupportedVersion),
+        }
+    }
+
+    /// Return the most restrictive message format the given body satisfies in
+    /// the given version, which is the format [`OffchainMessage::new`] selects
+    pub fn minimal_format(
+        version: u8,
+        message: &[u8],
+    ) -> Result<MessageFormat, OffchainMessageError> {
+        match version {
+            0 => v0::OffchainMessage::minimal_format(message),
+            _ => Err(OffchainMessageError::UnsupportedVersion),
         }
     }
 
+    /// Serialize a new off-chain message with the given version and message
+    /// body into `buf`, replacing its contents.
+    ///
+    /// The body is validated exactly as in [`OffchainMessage::new`], but no
+    /// `OffchainMessage` is constructed and the serialized form is written
+    /// into the caller's buffer. Reusing the same buffer across calls avoids
+    /// allocating for each serialized message.
+    pub fn new_in(
+        version: u8,
+        message: &[u8],
+        buf: &mut Vec<u8>,
+    ) -> Result<(), OffchainMessageError> {
+        match version {
+            0 => {
+                let format = v0::OffchainMessage::minimal_format(message)?;
+                buf.clear();
+                buf.extend_from_slice(Self::SIGNING_DOMAIN);
+                buf.push(0);
+                v0::OffchainMessage::serialize_parts(format, message, buf);
+                Ok(())
+            }
+            _ => Err(OffchainMessageError::UnsupportedVersion),
+        }
+    }
+
+    /// Return the length of a serialized message with a body of
+    /// `message_len` bytes, including the full header.
+    ///
+    /// Only the v0 header exists, and it does not carry any signers, so the
+    /// length depends on the body length alone.
+    pub const fn serialized_len(message_len: usize) -> usize {
+        Self::HEADER_LEN
+            .saturating_add(v0::OffchainMessage::HEADER_LEN)
+            .saturating_add(message_len)
+    }
+
+    /// Return whether a body of `message_len` bytes is short enough to be
+    /// signed on a Ledger device.
+    ///
+    /// Ledger-compatible formats additionally restrict the body contents; see
+    /// [`MessageFormat`].
+    pub const fn fits_ledger(message_len: usize) -> bool {
+        message_len <= v0::OffchainMessage::MAX_LEN_LEDGER
+    }
+
     /// Serialize the off-chain message to bytes including full header
     pub fn serialize(&self) -> Result<Vec<u8>, SanitizeError> {
+        let mut data = Vec::new();
+        self.serialize_to_vec(&mut data);
+        Ok(data)
+    }
+
+    /// Append the off-chain message including full header to `data`
+    fn serialize_to_vec(&self, data: &mut Vec<u8>) {
         // serialize signing domain
-        let mut data = Self::SIGNING_DOMAIN.to_vec();
+        data.extend_from_slice(Self::SIGNING_DOMAIN);
 
         // serialize version and call version specific serializer
         match self {
             Self::V0(msg) => {
                 data.push(0);
-                msg.serialize(&mut data)?;
+                v0::OffchainMessage::serialize_parts(msg.get_format(), msg.get_message(), data)
+            }
+        }
+    }
+
+    /// Serialize the off-chain message including full header into `writer`
+    #[cfg(feature = "std")]
+    pub fn serialize_into<W: Write>(&self, writer: &mut W) -> Result<(), SanitizeError> {
+        // serialize signing domain
+        writer
+            .write_all(Self::SIGNING_DOMAIN)
+            .map_err(|_| SanitizeError::InvalidValue)?;
+
+        // serialize version and call version specific serializer
+        match self {
+            Self::V0(msg) => {
+                writer
+                    .write_all(&[0])
+                    .map_err(|_| SanitizeError::InvalidValue)?;
+                msg.serialize_into(writer)
             }
         }
-        Ok(data)
     }
 
     /// Deserialize the off-chain message from bytes that include full header
-    pub fn deserialize(data: &[u8]) -> Result<Self, SanitizeError> {
+    pub fn deserialize(data: &[u8]) -> Result<Self, OffchainMessageError> {
+        Self::deserialize_verbose(data).map_err(|(err, _)| err)
+    }
+
+    /// Deserialize the off-chain message like [`OffchainMessage::deserialize`],
+    /// also reporting the offset into `data` at which parsing failed
+    ///
+    /// A mismatched signing domain is reported at its first differing byte
+    /// and an unsupported version at the version byte. Offsets reported by
+    /// the versioned parser, such as
+    /// [`v0::OffchainMessage::deserialize_verbose`], are shifted to be
+    /// relative to the start of `data`.
+    pub fn deserialize_verbose(data: &[u8]) -> Result<Self, (OffchainMessageError, usize)> {
         if data.len() <= Self::HEADER_LEN {
-            return Err(SanitizeError::ValueOutOfBounds);
+            return Err((OffchainMessageError::TooShort, data.len()));
+        }
+        if let Some(index) = data
+            .iter()
+            .zip(Self::SIGNING_DOMAIN)
+            .position(|(byte, expected)| byte != expected)
+        {
+            return Err((OffchainMessageError::InvalidSigningDomain, index));
         }
         let version = data[Self::SIGNING_DOMAIN.len()];
-        let data = &data[Self::SIGNING_DOMAIN.len().saturating_add(1)..];
+        let data = &data[Self::HEADER_LEN..];
         match version {
-            0 => Ok(Self::V0(v0::OffchainMessage::deserialize(data)?)),
-            _ => Err(SanitizeError::ValueOutOfBounds),
+            0 => v0::OffchainMessage::deserialize_verbose(data)
+                .map(Self::V0)
+                .map_err(|(err, offset)| (err, Self::HEADER_LEN.saturating_add(offset))),
+            _ => Err((
+                OffchainMessageError::UnsupportedVersion,
+                Self::SIGNING_DOMAIN.len(),
+            )),
         }
     }
 
@@ -211,6 +480,16 @@ impl OffchainMessage {
         }
     }
 
+    /// Serialize the off-chain message and compute its hash, serializing
+    /// only once
+    pub fn serialize_and_hash(&self) -> Result<(Vec<u8>, Hash), SanitizeError> {
+        let data = self.serialize()?;
+        let hash = match self {
+            Self::V0(_) => v0::OffchainMessage::hash(&data)?,
+        };
+        Ok((data, hash))
+    }
+
     pub fn get_version(&self) -> u8 {
         match self {
             Self::V0(_) => 0,
@@ -229,7 +508,15 @@ impl OffchainMessage {
         }
     }
 
+    /// Return the message body, independent of the message version
+    pub fn body(&self) -> &[u8] {
+        match self {
+            Self::V0(msg) => msg.get_message(),
+        }
+    }
+
     /// Sign the message with provided keypair
+    #[cfg(feature = "std")]
     pub fn sign(&self, signer: &dyn Signer) -> Result<Signature, SanitizeError> {
         Ok(signer.sign_message(&self.serialize()?))
     }
@@ -243,11 +530,204 @@ impl OffchainMessage {
     ) -> Result<bool, SanitizeError> {
         Ok(signature.verify(signer.as_ref(), &self.serialize()?))
     }
+
+    /// Serialize the message and its signatures into a detached signature
+    /// ("sidecar") file.
+    ///
+    /// The file layout is:
+    ///
+    /// | bytes   | field                                          |
+    /// |---------|------------------------------------------------|
+    /// | 8       | [`SIGNED_FILE_MAGIC`](Self::SIGNED_FILE_MAGIC) |
+    /// | 1       | [`SIGNED_FILE_VERSION`](Self::SIGNED_FILE_VERSION) |
+    /// | 1       | number of signatures                           |
+    /// | 64 * n  | signatures                                     |
+    /// | rest    | serialized off-chain message, full header      |
+    ///
+    /// At most `u8::MAX` signatures can be stored; returns
+    /// [`OffchainMessageError::TooManySigners`] for more.
+    pub fn to_signed_file(
+        &self,
+        signatures: &[Signature],
+    ) -> Result<Vec<u8>, OffchainMessageError> {
+        let num_signatures =
+            u8::try_from(signatures.len()).map_err(|_| OffchainMessageError::TooManySigners)?;
+        let mut data = Vec::with_capacity(
+            Self::SIGNED_FILE_HEADER_LEN
+                .saturating_add(signatures.len().saturating_mul(SIGNATURE_BYTES))
+                .saturating_add(Self::serialized_len(self.body().len())),
+        );
+        data.extend_from_slice(&Self::SIGNED_FILE_MAGIC);
+        data.push(Self::SIGNED_FILE_VERSION);
+        data.push(num_signatures);
+        for signature in signatures {
+            data.extend_from_slice(signature.as_ref());
+        }
+        self.serialize_to_vec(&mut data);
+        Ok(data)
+    }
+
+    /// Parse a detached signature file produced by
+    /// [`to_signed_file`](Self::to_signed_file), returning the message and
+    /// its signatures.
+    ///
+    /// The signatures are not verified.
+    pub fn from_signed_file(data: &[u8]) -> Result<(Self, Vec<Signature>), OffchainMessageError> {
+        if data.len() < Self::SIGNED_FILE_HEADER_LEN {
+            return Err(OffchainMessageError::TooShort);
+        }
+        let (magic, data) = data.split_at(Self::SIGNED_FILE_MAGIC.len());
+        if magic != Self::SIGNED_FILE_MAGIC {
+            return Err(OffchainMessageError::InvalidMagic);
+        }
+        if data[0] != Self::SIGNED_FILE_VERSION {
+            return Err(OffchainMessageError::UnsupportedVersion);
+        }
+        let num_signatures = usize::from(data[1]);
+        let data = &data[2..];
+        let signatures_len = num_signatures.saturating_mul(SIGNATURE_BYTES);
+        if data.len() < signatures_len {
+            return Err(OffchainMessageError::TooShort);
+        }
+        let (signatures, message) = data.split_at(signatures_len);
+        let signatures = signatures
+            .chunks_exact(SIGNATURE_BYTES)
+            .map(|bytes| Signature::try_from(bytes).expect("chunk is SIGNATURE_BYTES long"))
+            .collect();
+        Ok((Self::deserialize(message)?, signatures))
+    }
+}
+
+/// Builder for [`OffchainMessage`].
+///
+/// The version defaults to 0 and the format is detected from the body unless
+/// one is set explicitly, in which case [`OffchainMessage::new_with_format`]
+/// is used instead of [`OffchainMessage::new`].
+#[derive(Debug, Default, Clone)]
+pub struct OffchainMessageBuilder<'a> {
+    version: u8,
+    format: Option<MessageFormat>,
+    body: &'a [u8],
+}
+
+impl<'a> OffchainMessageBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the header version
+    pub fn version(mut self, version: u8) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Set the message format instead of detecting it from the body
+    pub fn format(mut self, format: MessageFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Set the message body
+    pub fn body(mut self, body: &'a [u8]) -> Self {
+        self.body = body;
+        self
+    }
+
+    /// Construct the off-chain message, validating the body against the
+    /// version and format
+    pub fn build(&self) -> Result<OffchainMessage, OffchainMessageError> {
+        match self.format {
+            Some(format) => OffchainMessage::new_with_format(self.version, format, self.body),
+            None => OffchainMessage::new(self.version, self.body),
+        }
+    }
+}
+
+impl fmt::Display for OffchainMessage {
+    /// Formats the serialized off-chain message as a base58 string
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let data = self.serialize().map_err(|_| fmt::Error)?;
+        f.write_str(&bs58::encode(data).into_string())
+    }
+}
+
+impl FromStr for OffchainMessage {
+    type Err = SanitizeError;
+
+    /// Parses an off-chain message from the base58 string of its serialized form
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = bs58::decode(s)
+            .into_vec()
+            .map_err(|_| SanitizeError::InvalidValue)?;
+        Ok(Self::deserialize(&data)?)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for OffchainMessage {
+    /// Serializes the off-chain message as a byte vector holding its
+    /// serialized form, so it composes into length-prefixed formats
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+
+        let data = OffchainMessage::serialize(self).map_err(S::Error::custom)?;
+        serde::Serialize::serialize(&data, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OffchainMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let data = <Vec<u8> as serde::Deserialize>::deserialize(deserializer)?;
+        OffchainMessage::deserialize(&data).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for v0::OffchainMessage {
+    /// Serializes the v0 message as a byte vector holding its serialized
+    /// form, starting with the v0 header
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+
+        let mut data = Vec::new();
+        v0::OffchainMessage::serialize(self, &mut data).map_err(S::Error::custom)?;
+        serde::Serialize::serialize(&data, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for v0::OffchainMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let data = <Vec<u8> as serde::Deserialize>::deserialize(deserializer)?;
+        v0::OffchainMessage::deserialize(&data).map_err(D::Error::custom)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use {super::*, solana_keypair::Keypair, std::str::FromStr};
+    use {
+        super::*,
+        alloc::vec,
+        solana_keypair::Keypair,
+        std::{io::Cursor, string::ToString},
+    };
 
     #[test]
     fn test_offchain_message_ascii() {
@@ -255,6 +735,7 @@ mod tests {
         assert_eq!(message.get_version(), 0);
         assert_eq!(message.get_format(), MessageFormat::RestrictedAscii);
         assert_eq!(message.get_message().as_slice(), b"Test Message");
+        assert_eq!(message.body(), b"Test Message");
         assert!(
             matches!(message, OffchainMessage::V0(ref msg) if msg.get_format() == MessageFormat::RestrictedAscii)
         );
@@ -265,6 +746,10 @@ mod tests {
         let hash = Hash::from_str("HG5JydBGjtjTfD3sSn21ys5NTWPpXzmqifiGC2BVUjkD").unwrap();
         assert_eq!(message.serialize().unwrap(), serialized);
         assert_eq!(message.hash().unwrap(), hash);
+        assert_eq!(
+            message.serialize_and_hash().unwrap(),
+            (serialized.to_vec(), hash)
+        );
         assert_eq!(message, OffchainMessage::deserialize(&serialized).unwrap());
     }
 
@@ -289,9 +774,566 @@ mod tests {
         let hash = Hash::from_str("6GXTveatZQLexkX4WeTpJ3E7uk1UojRXpKp43c4ArSun").unwrap();
         assert_eq!(message.serialize().unwrap(), serialized);
         assert_eq!(message.hash().unwrap(), hash);
+        assert_eq!(
+            message.serialize_and_hash().unwrap(),
+            (serialized.to_vec(), hash)
+        );
         assert_eq!(message, OffchainMessage::deserialize(&serialized).unwrap());
     }
 
+    #[test]
+    fn test_offchain_message_base58_round_trip() {
+        for message in [
+            OffchainMessage::new(0, b"Test Message").unwrap(),
+            OffchainMessage::new(0, "Тестовое сообщение".as_bytes()).unwrap(),
+        ] {
+            let encoded = message.to_string();
+            assert_eq!(
+                encoded,
+                bs58::encode(message.serialize().unwrap()).into_string()
+            );
+            assert_eq!(OffchainMessage::from_str(&encoded).unwrap(), message);
+        }
+        assert_eq!(
+            OffchainMessage::from_str("not base58: 0OIl"),
+            Err(SanitizeError::InvalidValue)
+        );
+    }
+
+    #[test]
+    fn test_offchain_message_errors() {
+        assert_eq!(
+            OffchainMessage::new(0, b""),
+            Err(OffchainMessageError::EmptyMessage)
+        );
+        assert_eq!(
+            OffchainMessage::new(0, &[0xff]),
+            Err(OffchainMessageError::NonUtf8)
+        );
+        assert_eq!(
+            OffchainMessage::new(0, &vec![b'a'; v0::OffchainMessage::MAX_LEN + 1]),
+            Err(OffchainMessageError::MessageTooLarge)
+        );
+        assert_eq!(
+            OffchainMessage::new(1, b"Test Message"),
+            Err(OffchainMessageError::UnsupportedVersion)
+        );
+
+        let serialized = OffchainMessage::new(0, b"Test Message")
+            .unwrap()
+            .serialize()
+            .unwrap();
+        assert_eq!(
+            OffchainMessage::deserialize(&serialized[..OffchainMessage::HEADER_LEN]),
+            Err(OffchainMessageError::TooShort)
+        );
+        for i in [0, 1, OffchainMessage::SIGNING_DOMAIN.len() - 1] {
+            let mut bad_domain = serialized.clone();
+            bad_domain[i] ^= 0x01;
+            assert_eq!(
+                OffchainMessage::deserialize(&bad_domain),
+                Err(OffchainMessageError::InvalidSigningDomain)
+            );
+        }
+        assert_eq!(
+            SanitizeError::from(OffchainMessageError::InvalidSigningDomain),
+            SanitizeError::InvalidValue
+        );
+        let mut bad_version = serialized.clone();
+        bad_version[OffchainMessage::SIGNING_DOMAIN.len()] = 1;
+        assert_eq!(
+            OffchainMessage::deserialize(&bad_version),
+            Err(OffchainMessageError::UnsupportedVersion)
+        );
+        let mut bad_format = serialized.clone();
+        bad_format[OffchainMessage::HEADER_LEN] = 3;
+        assert_eq!(
+            OffchainMessage::deserialize(&bad_format),
+            Err(OffchainMessageError::InvalidFormat)
+        );
+        let mut wrong_format = serialized.clone();
+        wrong_format[serialized.len() - 1] = 0x7f;
+        assert_eq!(
+            OffchainMessage::deserialize(&wrong_format),
+            Err(OffchainMessageError::InvalidFormat)
+        );
+        assert_eq!(
+            OffchainMessage::deserialize(&serialized[..serialized.len() - 1]),
+            Err(OffchainMessageError::LengthMismatch)
+        );
+        let mut trailing = serialized.clone();
+        trailing.push(b'!');
+        assert_eq!(
+            OffchainMessage::deserialize(&trailing),
+            Err(OffchainMessageError::LengthMismatch)
+        );
+        assert_eq!(
+            v0::OffchainMessage::deserialize(&trailing[OffchainMessage::HEADER_LEN..]),
+            Err(OffchainMessageError::LengthMismatch)
+        );
+        assert_eq!(
+            SanitizeError::from(OffchainMessageError::LengthMismatch),
+            SanitizeError::InvalidValue
+        );
+
+        // errors still map onto the previous `SanitizeError` values
+        assert_eq!(
+            SanitizeError::from(OffchainMessageError::EmptyMessage),
+            SanitizeError::InvalidValue
+        );
+        assert_eq!(
+            SanitizeError::from(OffchainMessageError::MessageTooLarge),
+            SanitizeError::ValueOutOfBounds
+        );
+    }
+
+    #[test]
+    fn test_offchain_message_deserialize_verbose() {
+        let serialized = OffchainMessage::new(0, b"Test Message")
+            .unwrap()
+            .serialize()
+            .unwrap();
+        let header_len = OffchainMessage::HEADER_LEN;
+        let body_start = header_len + v0::OffchainMessage::HEADER_LEN;
+        assert_eq!(
+            OffchainMessage::deserialize_verbose(&serialized),
+            Ok(OffchainMessage::deserialize(&serialized).unwrap())
+        );
+
+        assert_eq!(
+            OffchainMessage::deserialize_verbose(&serialized[..header_len]),
+            Err((OffchainMessageError::TooShort, header_len))
+        );
+        let mut bad_domain = serialized.clone();
+        bad_domain[5] ^= 0x01;
+        assert_eq!(
+            OffchainMessage::deserialize_verbose(&bad_domain),
+            Err((OffchainMessageError::InvalidSigningDomain, 5))
+        );
+        let mut bad_version = serialized.clone();
+        bad_version[header_len - 1] = 1;
+        assert_eq!(
+            OffchainMessage::deserialize_verbose(&bad_version),
+            Err((OffchainMessageError::UnsupportedVersion, header_len - 1))
+        );
+        let mut bad_format = serialized.clone();
+        bad_format[header_len] = 3;
+        assert_eq!(
+            OffchainMessage::deserialize_verbose(&bad_format),
+            Err((OffchainMessageError::InvalidFormat, header_len))
+        );
+        assert_eq!(
+            OffchainMessage::deserialize_verbose(&serialized[..serialized.len() - 1]),
+            Err((OffchainMessageError::LengthMismatch, header_len + 1))
+        );
+        let mut bad_body = serialized.clone();
+        bad_body[body_start + 4] = 0x7f;
+        assert_eq!(
+            OffchainMessage::deserialize_verbose(&bad_body),
+            Err((OffchainMessageError::InvalidFormat, body_start + 4))
+        );
+        assert_eq!(
+            v0::OffchainMessage::deserialize_verbose(&bad_body[header_len..]),
+            Err((
+                OffchainMessageError::InvalidFormat,
+                v0::OffchainMessage::HEADER_LEN + 4
+            ))
+        );
+
+        // a utf-8 body is reported at the first invalid sequence
+        let mut bad_utf8 = OffchainMessage::new(0, "Тестовое сообщение".as_bytes())
+            .unwrap()
+            .serialize()
+            .unwrap();
+        bad_utf8[body_start + 6] = 0xff;
+        assert_eq!(
+            OffchainMessage::deserialize_verbose(&bad_utf8),
+            Err((OffchainMessageError::InvalidFormat, body_start + 6))
+        );
+    }
+
+    #[test]
+    fn test_offchain_message_new_in() {
+        let mut buf = Vec::new();
+        for body in [
+            b"Test Message".as_slice(),
+            "Тестовое сообщение".as_bytes(),
+            b"Short",
+        ] {
+            OffchainMessage::new_in(0, body, &mut buf).unwrap();
+            let message = OffchainMessage::new(0, body).unwrap();
+            assert_eq!(buf, message.serialize().unwrap());
+        }
+        assert_eq!(
+            OffchainMessage::new_in(0, b"", &mut buf),
+            Err(OffchainMessageError::EmptyMessage)
+        );
+        assert_eq!(
+            OffchainMessage::new_in(1, b"Test Message", &mut buf),
+            Err(OffchainMessageError::UnsupportedVersion)
+        );
+    }
+
+    #[test]
+    fn test_offchain_message_accessors() {
+        // reads every property without matching on the version variant
+        fn describe(message: &OffchainMessage) -> (u8, MessageFormat, &[u8]) {
+            (message.get_version(), message.get_format(), message.body())
+        }
+
+        let message = OffchainMessage::new(0, "Тестовое сообщение".as_bytes()).unwrap();
+        assert_eq!(
+            describe(&message),
+            (
+                0,
+                MessageFormat::LimitedUtf8,
+                "Тестовое сообщение".as_bytes()
+            )
+        );
+    }
+
+    #[test]
+    fn test_minimal_format() {
+        let max_len_ledger = v0::OffchainMessage::MAX_LEN_LEDGER;
+        assert_eq!(
+            v0::OffchainMessage::minimal_format(b"Test Message"),
+            Ok(MessageFormat::RestrictedAscii)
+        );
+        assert_eq!(
+            v0::OffchainMessage::minimal_format("Тестовое сообщение".as_bytes()),
+            Ok(MessageFormat::LimitedUtf8)
+        );
+        assert_eq!(
+            v0::OffchainMessage::minimal_format(&vec![b'a'; max_len_ledger + 1]),
+            Ok(MessageFormat::ExtendedUtf8)
+        );
+        assert_eq!(
+            v0::OffchainMessage::minimal_format(&vec![b'a'; v0::OffchainMessage::MAX_LEN + 1]),
+            Err(OffchainMessageError::MessageTooLarge)
+        );
+        assert_eq!(
+            v0::OffchainMessage::minimal_format(&[0xff]),
+            Err(OffchainMessageError::NonUtf8)
+        );
+
+        // the top-level entry point dispatches on the version
+        assert_eq!(
+            OffchainMessage::minimal_format(0, "Тестовое сообщение".as_bytes()),
+            Ok(MessageFormat::LimitedUtf8)
+        );
+        assert_eq!(
+            OffchainMessage::minimal_format(0, b"Test Message"),
+            OffchainMessage::new(0, b"Test Message").map(|message| message.get_format())
+        );
+        assert_eq!(
+            OffchainMessage::minimal_format(0, &[]),
+            Err(OffchainMessageError::EmptyMessage)
+        );
+        assert_eq!(
+            OffchainMessage::minimal_format(1, b"Test Message"),
+            Err(OffchainMessageError::UnsupportedVersion)
+        );
+    }
+
+    #[test]
+    fn test_detect_format_explained() {
+        let max_len_ledger = v0::OffchainMessage::MAX_LEN_LEDGER;
+        assert_eq!(
+            v0::OffchainMessage::detect_format_explained(b"Test Message"),
+            Ok((
+                MessageFormat::RestrictedAscii,
+                FormatReason::AsciiUnderLimit
+            ))
+        );
+        assert_eq!(
+            v0::OffchainMessage::detect_format_explained(&vec![b'a'; max_len_ledger]),
+            Ok((
+                MessageFormat::RestrictedAscii,
+                FormatReason::AsciiUnderLimit
+            ))
+        );
+        assert_eq!(
+            v0::OffchainMessage::detect_format_explained("Тестовое сообщение".as_bytes()),
+            Ok((MessageFormat::LimitedUtf8, FormatReason::Utf8UnderLimit))
+        );
+        assert_eq!(
+            v0::OffchainMessage::detect_format_explained(&vec![b'a'; max_len_ledger + 1]),
+            Ok((
+                MessageFormat::ExtendedUtf8,
+                FormatReason::ExceededLedgerLimit
+            ))
+        );
+        assert_eq!(
+            v0::OffchainMessage::detect_format_explained(b""),
+            Err(OffchainMessageError::EmptyMessage)
+        );
+        assert_eq!(
+            v0::OffchainMessage::detect_format_explained(&[0xff]),
+            Err(OffchainMessageError::NonUtf8)
+        );
+    }
+
+    #[test]
+    fn test_offchain_message_new_with_format() {
+        let message =
+            OffchainMessage::new_with_format(0, MessageFormat::LimitedUtf8, b"Test Message")
+                .unwrap();
+        assert_eq!(message.get_format(), MessageFormat::LimitedUtf8);
+        assert_eq!(message.body(), b"Test Message");
+        let serialized = message.serialize().unwrap();
+        assert_eq!(serialized[OffchainMessage::HEADER_LEN], 1);
+        assert_eq!(OffchainMessage::deserialize(&serialized).unwrap(), message);
+
+        let message =
+            OffchainMessage::new_with_format(0, MessageFormat::ExtendedUtf8, b"Test Message")
+                .unwrap();
+        assert_eq!(message.get_format(), MessageFormat::ExtendedUtf8);
+
+        assert_eq!(
+            OffchainMessage::new_with_format(
+                0,
+                MessageFormat::RestrictedAscii,
+                "Тестовое сообщение".as_bytes()
+            ),
+            Err(OffchainMessageError::InvalidFormat)
+        );
+        assert_eq!(
+            OffchainMessage::new_with_format(
+                0,
+                MessageFormat::LimitedUtf8,
+                &vec![b'a'; v0::OffchainMessage::MAX_LEN_LEDGER + 1]
+            ),
+            Err(OffchainMessageError::InvalidFormat)
+        );
+        assert_eq!(
+            OffchainMessage::new_with_format(0, MessageFormat::ExtendedUtf8, &[0xff]),
+            Err(OffchainMessageError::InvalidFormat)
+        );
+        assert_eq!(
+            OffchainMessage::new_with_format(0, MessageFormat::RestrictedAscii, b""),
+            Err(OffchainMessageError::EmptyMessage)
+        );
+        assert_eq!(
+            OffchainMessage::new_with_format(
+                0,
+                MessageFormat::ExtendedUtf8,
+                &vec![b'a'; v0::OffchainMessage::MAX_LEN + 1]
+            ),
+            Err(OffchainMessageError::MessageTooLarge)
+        );
+        assert_eq!(
+            OffchainMessage::new_with_format(1, MessageFormat::RestrictedAscii, b"Test"),
+            Err(OffchainMessageError::UnsupportedVersion)
+        );
+    }
+
+    #[test]
+    fn test_offchain_message_serialized_len() {
+        let message = OffchainMessage::new(0, b"Test Message").unwrap();
+        assert_eq!(
+            OffchainMessage::serialized_len(message.body().len()),
+            message.serialize().unwrap().len()
+        );
+        assert_eq!(
+            OffchainMessage::serialized_len(v0::OffchainMessage::MAX_LEN_LEDGER),
+            solana_packet::PACKET_DATA_SIZE
+        );
+
+        assert!(OffchainMessage::fits_ledger(1));
+        assert!(OffchainMessage::fits_ledger(
+            v0::OffchainMessage::MAX_LEN_LEDGER
+        ));
+        assert!(!OffchainMessage::fits_ledger(
+            v0::OffchainMessage::MAX_LEN_LEDGER + 1
+        ));
+    }
+
+    #[test]
+    fn test_offchain_message_serialize_into() {
+        let message = OffchainMessage::new(0, "Тестовое сообщение".as_bytes()).unwrap();
+        let serialized = message.serialize().unwrap();
+
+        let mut buf = Vec::new();
+        message.serialize_into(&mut buf).unwrap();
+        assert_eq!(buf, serialized);
+
+        let mut cursor = Cursor::new([0u8; 128]);
+        message.serialize_into(&mut cursor).unwrap();
+        assert_eq!(cursor.position() as usize, serialized.len());
+        assert_eq!(&cursor.get_ref()[..serialized.len()], serialized.as_slice());
+
+        let mut too_small = Cursor::new([0u8; 8]);
+        assert_eq!(
+            message.serialize_into(&mut too_small),
+            Err(SanitizeError::InvalidValue)
+        );
+    }
+
+    #[test]
+    fn test_offchain_message_signed_file_round_trip() {
+        let message = OffchainMessage::new(0, b"Test Message").unwrap();
+        let signatures = [
+            message.sign(&Keypair::new()).unwrap(),
+            message.sign(&Keypair::new()).unwrap(),
+        ];
+
+        let file = message.to_signed_file(&signatures).unwrap();
+        assert_eq!(&file[..8], b"SOLSIGN\0");
+        assert_eq!(file[8], OffchainMessage::SIGNED_FILE_VERSION);
+        assert_eq!(file[9], 2);
+        let (parsed, parsed_signatures) = OffchainMessage::from_signed_file(&file).unwrap();
+        assert_eq!(parsed, message);
+        assert_eq!(parsed_signatures, signatures);
+
+        let file = message.to_signed_file(&[]).unwrap();
+        let (parsed, parsed_signatures) = OffchainMessage::from_signed_file(&file).unwrap();
+        assert_eq!(parsed, message);
+        assert!(parsed_signatures.is_empty());
+    }
+
+    #[test]
+    fn test_offchain_message_signed_file_errors() {
+        let message = OffchainMessage::new(0, b"Test Message").unwrap();
+        let signature = message.sign(&Keypair::new()).unwrap();
+        let file = message.to_signed_file(&[signature]).unwrap();
+
+        let mut wrong_magic = file.clone();
+        wrong_magic[0] = b'X';
+        assert_eq!(
+            OffchainMessage::from_signed_file(&wrong_magic),
+            Err(OffchainMessageError::InvalidMagic)
+        );
+
+        let mut wrong_version = file.clone();
+        wrong_version[8] = 1;
+        assert_eq!(
+            OffchainMessage::from_signed_file(&wrong_version),
+            Err(OffchainMessageError::UnsupportedVersion)
+        );
+
+        assert_eq!(
+            OffchainMessage::from_signed_file(&file[..9]),
+            Err(OffchainMessageError::TooShort)
+        );
+        assert_eq!(
+            OffchainMessage::from_signed_file(&file[..40]),
+            Err(OffchainMessageError::TooShort)
+        );
+
+        assert_eq!(
+            message.to_signed_file(&[signature; 256]),
+            Err(OffchainMessageError::TooManySigners)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_offchain_message_bincode_round_trip() {
+        #[derive(Debug, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+        struct Wrapper {
+            id: u64,
+            msg: OffchainMessage,
+            trailer: u8,
+        }
+
+        let wrapper = Wrapper {
+            id: 42,
+            msg: OffchainMessage::new(0, "Тестовое сообщение".as_bytes()).unwrap(),
+            trailer: 7,
+        };
+        let data = bincode::serialize(&wrapper).unwrap();
+        assert_eq!(bincode::deserialize::<Wrapper>(&data).unwrap(), wrapper);
+
+        let mut corrupted = bincode::serialize(&Wrapper {
+            id: 42,
+            msg: OffchainMessage::new(0, b"Test Message").unwrap(),
+            trailer: 7,
+        })
+        .unwrap();
+        // first byte of the message body, after the id and the length prefix
+        corrupted[8 + 8 + OffchainMessage::HEADER_LEN + v0::OffchainMessage::HEADER_LEN] = 0xff;
+        assert!(bincode::deserialize::<Wrapper>(&corrupted).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_offchain_message_json_round_trip() {
+        let message = OffchainMessage::new(0, b"Test Message").unwrap();
+        let json = serde_json::to_string(&message).unwrap();
+        // the wire bytes, not the enum shape
+        assert_eq!(
+            json,
+            serde_json::to_string(&message.serialize().unwrap()).unwrap()
+        );
+        assert_eq!(
+            serde_json::from_str::<OffchainMessage>(&json).unwrap(),
+            message
+        );
+
+        let OffchainMessage::V0(message_v0) = message;
+        let json = serde_json::to_string(&message_v0).unwrap();
+        let mut data = Vec::new();
+        message_v0.serialize(&mut data).unwrap();
+        assert_eq!(json, serde_json::to_string(&data).unwrap());
+        assert_eq!(
+            serde_json::from_str::<v0::OffchainMessage>(&json).unwrap(),
+            message_v0
+        );
+
+        assert!(serde_json::from_str::<OffchainMessage>("[1,2,3]").is_err());
+        assert!(serde_json::from_str::<v0::OffchainMessage>("[0,1,0,255]").is_err());
+    }
+
+    #[test]
+    fn test_offchain_message_hash_set() {
+        let mut messages = std::collections::HashSet::new();
+        assert!(messages.insert(OffchainMessage::new(0, b"Test Message").unwrap()));
+        assert!(!messages.insert(OffchainMessage::new(0, b"Test Message").unwrap()));
+        assert!(messages.insert(OffchainMessage::new(0, b"Other Message").unwrap()));
+        // the same body with a different format is a different message
+        assert!(messages.insert(
+            OffchainMessage::new_with_format(0, MessageFormat::ExtendedUtf8, b"Test Message")
+                .unwrap()
+        ));
+        assert_eq!(messages.len(), 3);
+    }
+
+    #[test]
+    fn test_offchain_message_builder() {
+        let message = OffchainMessageBuilder::new()
+            .body(b"Test Message")
+            .build()
+            .unwrap();
+        assert_eq!(message, OffchainMessage::new(0, b"Test Message").unwrap());
+
+        let message = OffchainMessageBuilder::new()
+            .version(0)
+            .format(MessageFormat::ExtendedUtf8)
+            .body(b"Test Message")
+            .build()
+            .unwrap();
+        assert_eq!(message.get_format(), MessageFormat::ExtendedUtf8);
+
+        assert_eq!(
+            OffchainMessageBuilder::new().build(),
+            Err(OffchainMessageError::EmptyMessage)
+        );
+        assert_eq!(
+            OffchainMessageBuilder::new()
+                .version(1)
+                .body(b"Test Message")
+                .build(),
+            Err(OffchainMessageError::UnsupportedVersion)
+        );
+        assert_eq!(
+            OffchainMessageBuilder::new()
+                .format(MessageFormat::RestrictedAscii)
+                .body("Тестовое сообщение".as_bytes())
+                .build(),
+            Err(OffchainMessageError::InvalidFormat)
+        );
+    }
+
     #[test]
     fn test_offchain_message_sign_and_verify() {
         let message = OffchainMessage::new(0, b"Test Message").unwrap();
@@ -0,0 +1,21 @@
+//! Helpers for [EIP-712] typed structured data.
+//!
+//! Computing the domain separator and struct hash depends on the type being
+//! signed and is left to the caller; this module combines them into the
+//! digest that is signed, e.g. with [`sign_prehashed`], and recovered from,
+//! e.g. with [`verify_eip712_signature`].
+//!
+//! [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+//! [`sign_prehashed`]: crate::sign_prehashed
+//! [`verify_eip712_signature`]: crate::verify_eip712_signature
+
+use digest::Digest;
+
+/// Computes the EIP-712 digest `keccak256(0x19 || 0x01 || domain_separator || struct_hash)`.
+pub fn encode_digest(domain_separator: &[u8; 32], struct_hash: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = sha3::Keccak256::new();
+    hasher.update([0x19, 0x01]);
+    hasher.update(domain_separator);
+    hasher.update(struct_hash);
+    hasher.finalize().into()
+}
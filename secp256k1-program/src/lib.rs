@@ -786,6 +786,8 @@
 //! }
 //! ```
 
+pub mod eip712;
+
 #[cfg(feature = "serde")]
 use serde_derive::{Deserialize, Serialize};
 #[cfg(feature = "bincode")]
@@ -800,6 +802,33 @@ pub const SIGNATURE_SERIALIZED_SIZE: usize = 64;
 pub const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 11;
 pub const DATA_START: usize = SIGNATURE_OFFSETS_SERIALIZED_SIZE + 1;
 
+/// Errors produced by the secp256k1 helpers in this crate.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Secp256k1Error {
+    #[error("The underlying ECDSA operation failed")]
+    Ecdsa,
+    #[error("The signature is invalid")]
+    InvalidSignature,
+    #[error("The recovery ID is invalid")]
+    InvalidRecoveryId,
+    #[error("The message is larger than u16::MAX bytes")]
+    MessageTooLarge,
+    #[error("The instruction data is too short")]
+    InvalidInstructionDataSize,
+    #[error("The signature offsets point outside the instruction data")]
+    InvalidDataOffsets,
+    #[error("More than u8::MAX signatures were added")]
+    TooManySignatures,
+    #[error("The instruction data is too large for u16 offsets")]
+    InstructionDataTooLarge,
+}
+
+impl From<k256::ecdsa::Error> for Secp256k1Error {
+    fn from(_: k256::ecdsa::Error) -> Self {
+        Secp256k1Error::Ecdsa
+    }
+}
+
 /// Offsets of signature data within a secp256k1 instruction.
 ///
 /// See the [module documentation][md] for a complete description.
@@ -824,24 +853,181 @@ pub struct SecpSignatureOffsets {
     pub message_instruction_index: u8,
 }
 
+impl SecpSignatureOffsets {
+    /// Reads signature offsets from the first
+    /// [`SIGNATURE_OFFSETS_SERIALIZED_SIZE`] bytes of `data`.
+    ///
+    /// This is the inverse of the serialization used by
+    /// [`new_secp256k1_instruction_with_signature`]: the fields are stored in
+    /// declaration order, with `u16` fields in little-endian byte order.
+    pub fn from_slice(data: &[u8]) -> Result<Self, Secp256k1Error> {
+        let data: &[u8; SIGNATURE_OFFSETS_SERIALIZED_SIZE] = data
+            .get(..SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+            .and_then(|data| data.try_into().ok())
+            .ok_or(Secp256k1Error::InvalidInstructionDataSize)?;
+        Ok(Self {
+            signature_offset: u16::from_le_bytes([data[0], data[1]]),
+            signature_instruction_index: data[2],
+            eth_address_offset: u16::from_le_bytes([data[3], data[4]]),
+            eth_address_instruction_index: data[5],
+            message_data_offset: u16::from_le_bytes([data[6], data[7]]),
+            message_data_size: u16::from_le_bytes([data[8], data[9]]),
+            message_instruction_index: data[10],
+        })
+    }
+
+    /// Checks that the offsets refer to existing instructions and that the
+    /// signature with its recovery ID, the Ethereum address, and the message
+    /// all lie within those instructions' data.
+    ///
+    /// `data_len_by_instruction` holds the data length of every instruction
+    /// in the transaction, in order. Any violation is reported as
+    /// [`Secp256k1Error::InvalidDataOffsets`].
+    pub fn validate_against(
+        &self,
+        data_len_by_instruction: &[usize],
+    ) -> Result<(), Secp256k1Error> {
+        let check = |instruction_index: u8, offset: u16, size: usize| {
+            let data_len = data_len_by_instruction
+                .get(instruction_index as usize)
+                .ok_or(Secp256k1Error::InvalidDataOffsets)?;
+            if (offset as usize).saturating_add(size) <= *data_len {
+                Ok(())
+            } else {
+                Err(Secp256k1Error::InvalidDataOffsets)
+            }
+        };
+        check(
+            self.signature_instruction_index,
+            self.signature_offset,
+            SIGNATURE_SERIALIZED_SIZE.saturating_add(1),
+        )?;
+        check(
+            self.eth_address_instruction_index,
+            self.eth_address_offset,
+            HASHED_PUBKEY_SERIALIZED_SIZE,
+        )?;
+        check(
+            self.message_instruction_index,
+            self.message_data_offset,
+            self.message_data_size as usize,
+        )
+    }
+}
+
+/// Positions of the fields in the instruction data built by
+/// [`new_secp256k1_instruction_with_signature`].
+///
+/// The data is laid out as `[count][offsets][eth_address][signature][recovery_id][message]`,
+/// with every field referring to the same instruction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SingleSigLayout {
+    /// Offset to the 20-byte Ethereum address.
+    pub eth_address_offset: usize,
+    /// Offset to the 64-byte signature.
+    pub signature_offset: usize,
+    /// Offset to the 1-byte recovery ID.
+    pub recovery_id_offset: usize,
+    /// Offset to the start of the message data, which runs to the end of
+    /// the instruction data.
+    pub message_offset: usize,
+    /// Total length of the instruction data.
+    pub data_len: usize,
+}
+
+/// Returns the layout of the instruction data built by
+/// [`new_secp256k1_instruction_with_signature`].
+///
+/// The offsets are the same for every message; only `data_len` depends on
+/// `message_len`.
+pub const fn single_sig_layout(message_len: usize) -> SingleSigLayout {
+    let eth_address_offset = DATA_START;
+    let signature_offset = eth_address_offset.saturating_add(HASHED_PUBKEY_SERIALIZED_SIZE);
+    let recovery_id_offset = signature_offset.saturating_add(SIGNATURE_SERIALIZED_SIZE);
+    let message_offset = recovery_id_offset.saturating_add(1);
+    SingleSigLayout {
+        eth_address_offset,
+        signature_offset,
+        recovery_id_offset,
+        message_offset,
+        data_len: message_offset.saturating_add(message_len),
+    }
+}
+
 /// Signs a message from the given private key bytes
+///
+/// The message is hashed with Keccak-256 before signing. The signature is
+/// always normalized to a low `s` value, with the recovery ID adjusted to
+/// match, so it is accepted by programs that reject malleable signatures.
 pub fn sign_message(
     priv_key_bytes: &[u8; SECP256K1_PRIVATE_KEY_SIZE],
     message: &[u8],
 ) -> Result<([u8; SIGNATURE_SERIALIZED_SIZE], u8), Error> {
-    let priv_key = k256::ecdsa::SigningKey::from_slice(priv_key_bytes)
-        .map_err(|e| Error::from_source(format!("{e}")))?;
     let mut hasher = sha3::Keccak256::new();
     hasher.update(message);
     let message_hash = hasher.finalize();
     let mut message_hash_arr = [0u8; 32];
     message_hash_arr.copy_from_slice(message_hash.as_slice());
+    sign_prehashed(priv_key_bytes, &message_hash_arr)
+}
+
+/// Signs each of `messages` from the given private key bytes
+///
+/// This is equivalent to calling [`sign_message`] on each message in turn,
+/// returning the signatures and recovery IDs in the same order, but parses
+/// the private key only once.
+pub fn sign_messages(
+    priv_key_bytes: &[u8; SECP256K1_PRIVATE_KEY_SIZE],
+    messages: &[&[u8]],
+) -> Result<Vec<([u8; SIGNATURE_SERIALIZED_SIZE], u8)>, Error> {
+    let priv_key = parse_signing_key(priv_key_bytes)?;
+    messages
+        .iter()
+        .map(|message| {
+            let message_hash: [u8; 32] = sha3::Keccak256::digest(message).into();
+            sign_prehashed_with_key(&priv_key, &message_hash)
+        })
+        .collect()
+}
+
+/// Signs a 32-byte message hash from the given private key bytes
+///
+/// Unlike [`sign_message`], the input is signed as is, without hashing it
+/// with Keccak-256 first. The signature is normalized to a low `s` value in
+/// the same way. Use this when the digest was computed by another
+/// scheme, such as EIP-712; passing such a digest to [`sign_message`] would
+/// hash it twice.
+pub fn sign_prehashed(
+    priv_key_bytes: &[u8; SECP256K1_PRIVATE_KEY_SIZE],
+    message_hash: &[u8; 32],
+) -> Result<([u8; SIGNATURE_SERIALIZED_SIZE], u8), Error> {
+    let priv_key = parse_signing_key(priv_key_bytes)?;
+    sign_prehashed_with_key(&priv_key, message_hash)
+}
+
+fn parse_signing_key(
+    priv_key_bytes: &[u8; SECP256K1_PRIVATE_KEY_SIZE],
+) -> Result<k256::ecdsa::SigningKey, Error> {
+    k256::ecdsa::SigningKey::from_slice(priv_key_bytes)
+        .map_err(|e| Error::from_source(format!("{e}")))
+}
+
+fn sign_prehashed_with_key(
+    priv_key: &k256::ecdsa::SigningKey,
+    message_hash: &[u8; 32],
+) -> Result<([u8; SIGNATURE_SERIALIZED_SIZE], u8), Error> {
     let (signature, recovery_id) = priv_key
-        .sign_prehash_recoverable(&message_hash_arr)
+        .sign_prehash_recoverable(message_hash)
         .map_err(|e| Error::from_source(format!("{e}")))?;
     Ok((signature.to_bytes().into(), recovery_id.to_byte()))
 }
 
+/// Creates a secp256k1 instruction verifying `signature` over `message_arr`.
+///
+/// # Panics
+///
+/// Panics if `message_arr` is longer than `u16::MAX` bytes. Use
+/// [`try_new_secp256k1_instruction_with_signature`] to handle that case.
 #[cfg(feature = "bincode")]
 pub fn new_secp256k1_instruction_with_signature(
     message_arr: &[u8],
@@ -849,46 +1035,178 @@ pub fn new_secp256k1_instruction_with_signature(
     recovery_id: u8,
     eth_address: &[u8; HASHED_PUBKEY_SERIALIZED_SIZE],
 ) -> Instruction {
-    let instruction_data_len = DATA_START
-        .saturating_add(eth_address.len())
-        .saturating_add(signature.len())
-        .saturating_add(message_arr.len())
-        .saturating_add(1);
-    let mut instruction_data = vec![0; instruction_data_len];
-
-    let eth_address_offset = DATA_START;
-    instruction_data[eth_address_offset..eth_address_offset.saturating_add(eth_address.len())]
-        .copy_from_slice(eth_address);
+    try_new_secp256k1_instruction_with_signature(message_arr, signature, recovery_id, eth_address)
+        .expect("message too large for a secp256k1 instruction")
+}
 
-    let signature_offset = DATA_START.saturating_add(eth_address.len());
-    instruction_data[signature_offset..signature_offset.saturating_add(signature.len())]
-        .copy_from_slice(signature);
+/// Creates a secp256k1 instruction verifying `signature` over `message_arr`.
+///
+/// The offsets and the message size are stored as `u16`, so this fails with
+/// [`Secp256k1Error::MessageTooLarge`] rather than truncating them when
+/// `message_arr` is longer than `u16::MAX` bytes.
+#[cfg(feature = "bincode")]
+pub fn try_new_secp256k1_instruction_with_signature(
+    message_arr: &[u8],
+    signature: &[u8; SIGNATURE_SERIALIZED_SIZE],
+    recovery_id: u8,
+    eth_address: &[u8; HASHED_PUBKEY_SERIALIZED_SIZE],
+) -> Result<Instruction, Secp256k1Error> {
+    let message_data_size =
+        u16::try_from(message_arr.len()).map_err(|_| Secp256k1Error::MessageTooLarge)?;
 
-    instruction_data[signature_offset.saturating_add(signature.len())] = recovery_id;
+    let layout = single_sig_layout(message_arr.len());
+    let mut instruction_data = vec![0; layout.data_len];
 
-    let message_data_offset = signature_offset
-        .saturating_add(signature.len())
-        .saturating_add(1);
-    instruction_data[message_data_offset..].copy_from_slice(message_arr);
+    instruction_data[layout.eth_address_offset..layout.signature_offset]
+        .copy_from_slice(eth_address);
+    instruction_data[layout.signature_offset..layout.recovery_id_offset].copy_from_slice(signature);
+    instruction_data[layout.recovery_id_offset] = recovery_id;
+    instruction_data[layout.message_offset..].copy_from_slice(message_arr);
 
     let num_signatures = 1;
     instruction_data[0] = num_signatures;
     let offsets = SecpSignatureOffsets {
-        signature_offset: signature_offset as u16,
+        signature_offset: u16::try_from(layout.signature_offset)
+            .map_err(|_| Secp256k1Error::MessageTooLarge)?,
         signature_instruction_index: 0,
-        eth_address_offset: eth_address_offset as u16,
+        eth_address_offset: u16::try_from(layout.eth_address_offset)
+            .map_err(|_| Secp256k1Error::MessageTooLarge)?,
         eth_address_instruction_index: 0,
-        message_data_offset: message_data_offset as u16,
-        message_data_size: message_arr.len() as u16,
+        message_data_offset: u16::try_from(layout.message_offset)
+            .map_err(|_| Secp256k1Error::MessageTooLarge)?,
+        message_data_size,
         message_instruction_index: 0,
     };
     let writer = std::io::Cursor::new(&mut instruction_data[1..DATA_START]);
     bincode::serialize_into(writer, &offsets).unwrap();
 
-    Instruction {
+    Ok(Instruction {
         program_id: solana_sdk_ids::secp256k1_program::id(),
         accounts: vec![],
         data: instruction_data,
+    })
+}
+
+/// Builds a secp256k1 instruction verifying any number of signatures.
+///
+/// All signatures, addresses and messages are packed into the secp256k1
+/// instruction's own data after the offsets, each as
+/// `[signature][recovery_id][eth_address][message]`, as in the module
+/// documentation's `make_secp256k1_instruction_data` example.
+#[cfg(feature = "bincode")]
+#[derive(Debug, Default, Clone)]
+pub struct Secp256k1InstructionBuilder {
+    instruction_index: u8,
+    signatures: Vec<SecpSignature>,
+}
+
+/// A signature added to a [`Secp256k1InstructionBuilder`].
+#[cfg(feature = "bincode")]
+#[derive(Debug, Clone)]
+struct SecpSignature {
+    signature: [u8; SIGNATURE_SERIALIZED_SIZE],
+    recovery_id: u8,
+    eth_address: [u8; HASHED_PUBKEY_SERIALIZED_SIZE],
+    message: Vec<u8>,
+}
+
+#[cfg(feature = "bincode")]
+impl Secp256k1InstructionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the index the secp256k1 instruction will have in the
+    /// transaction, which the offsets refer to. Defaults to 0.
+    pub fn instruction_index(mut self, instruction_index: u8) -> Self {
+        self.instruction_index = instruction_index;
+        self
+    }
+
+    /// Add a signature over `message` by the key with the given Ethereum
+    /// address.
+    pub fn add_signature(
+        mut self,
+        message: &[u8],
+        signature: &[u8; SIGNATURE_SERIALIZED_SIZE],
+        recovery_id: u8,
+        eth_address: &[u8; HASHED_PUBKEY_SERIALIZED_SIZE],
+    ) -> Self {
+        self.signatures.push(SecpSignature {
+            signature: *signature,
+            recovery_id,
+            eth_address: *eth_address,
+            message: message.to_vec(),
+        });
+        self
+    }
+
+    /// Build the instruction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Secp256k1InstructionBuilder::try_build`] would fail.
+    pub fn build(&self) -> Instruction {
+        self.try_build()
+            .expect("signatures do not fit in a secp256k1 instruction")
+    }
+
+    /// Build the instruction.
+    ///
+    /// Fails with [`Secp256k1Error::TooManySignatures`] if more than
+    /// `u8::MAX` signatures were added, with
+    /// [`Secp256k1Error::MessageTooLarge`] if a message is longer than
+    /// `u16::MAX` bytes, and with [`Secp256k1Error::InstructionDataTooLarge`]
+    /// if an offset does not fit in a `u16`.
+    pub fn try_build(&self) -> Result<Instruction, Secp256k1Error> {
+        let num_signatures =
+            u8::try_from(self.signatures.len()).map_err(|_| Secp256k1Error::TooManySignatures)?;
+        let to_offset = |offset: usize| {
+            u16::try_from(offset).map_err(|_| Secp256k1Error::InstructionDataTooLarge)
+        };
+
+        // The signatures are packed after the offsets, which take a fixed
+        // size per signature.
+        let data_start = self
+            .signatures
+            .len()
+            .saturating_mul(SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+            .saturating_add(1);
+        let mut instruction_data = Vec::with_capacity(data_start);
+        instruction_data.push(num_signatures);
+        let mut signature_buffer = Vec::new();
+
+        for signature in &self.signatures {
+            let signature_offset = data_start.saturating_add(signature_buffer.len());
+            let eth_address_offset = signature_offset
+                .saturating_add(SIGNATURE_SERIALIZED_SIZE)
+                .saturating_add(1);
+            let message_data_offset =
+                eth_address_offset.saturating_add(HASHED_PUBKEY_SERIALIZED_SIZE);
+            let offsets = SecpSignatureOffsets {
+                signature_offset: to_offset(signature_offset)?,
+                signature_instruction_index: self.instruction_index,
+                eth_address_offset: to_offset(eth_address_offset)?,
+                eth_address_instruction_index: self.instruction_index,
+                message_data_offset: to_offset(message_data_offset)?,
+                message_data_size: u16::try_from(signature.message.len())
+                    .map_err(|_| Secp256k1Error::MessageTooLarge)?,
+                message_instruction_index: self.instruction_index,
+            };
+            bincode::serialize_into(&mut instruction_data, &offsets).unwrap();
+
+            signature_buffer.extend_from_slice(&signature.signature);
+            signature_buffer.push(signature.recovery_id);
+            signature_buffer.extend_from_slice(&signature.eth_address);
+            signature_buffer.extend_from_slice(&signature.message);
+        }
+        instruction_data.extend_from_slice(&signature_buffer);
+
+        Ok(Instruction {
+            program_id: solana_sdk_ids::secp256k1_program::id(),
+            accounts: vec![],
+            data: instruction_data,
+        })
     }
 }
 
@@ -901,3 +1219,799 @@ pub fn eth_address_from_pubkey(
     assert_eq!(addr.len(), HASHED_PUBKEY_SERIALIZED_SIZE);
     addr
 }
+
+/// Creates an Ethereum address from a `k256` verifying key.
+///
+/// This is equivalent to calling [`eth_address_from_pubkey`] with the
+/// uncompressed encoding of `key`, without its leading `0x04` tag byte.
+pub fn eth_address_from_verifying_key(
+    key: &k256::ecdsa::VerifyingKey,
+) -> [u8; HASHED_PUBKEY_SERIALIZED_SIZE] {
+    let encoded_point = key.to_encoded_point(false);
+    let mut pubkey = [0u8; SECP256K1_PUBKEY_SIZE];
+    pubkey.copy_from_slice(&encoded_point.as_bytes()[1..]);
+    eth_address_from_pubkey(&pubkey)
+}
+
+/// Formats an Ethereum address as a `0x`-prefixed hex string with the
+/// [EIP-55] mixed-case checksum, as shown by Ethereum tooling.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+pub fn eth_address_to_checksum_string(addr: &[u8; HASHED_PUBKEY_SERIALIZED_SIZE]) -> String {
+    let lowercase: String = addr.iter().map(|byte| format!("{byte:02x}")).collect();
+    let hash = sha3::Keccak256::digest(lowercase.as_bytes());
+    let mut checksummed = String::with_capacity(lowercase.len().saturating_add(2));
+    checksummed.push_str("0x");
+    // A letter is uppercased when the matching nibble of the hash is 8 or more
+    for (i, char) in lowercase.chars().enumerate() {
+        let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+        if nibble >= 8 {
+            checksummed.push(char.to_ascii_uppercase());
+        } else {
+            checksummed.push(char);
+        }
+    }
+    checksummed
+}
+
+/// Verifies an [EIP-712] typed-data signature against an expected Ethereum address.
+///
+/// Computes the EIP-712 digest of `domain_separator` and `struct_hash`,
+/// recovers the signing public key from `signature` and `recovery_id`, and
+/// checks that its Ethereum address equals `expected_eth_address`.
+///
+/// The recovery ID is the raw 0 or 1 value, not Ethereum's `v` value of 27
+/// or 28.
+///
+/// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+pub fn verify_eip712_signature(
+    domain_separator: &[u8; 32],
+    struct_hash: &[u8; 32],
+    signature: &[u8; SIGNATURE_SERIALIZED_SIZE],
+    recovery_id: u8,
+    expected_eth_address: &[u8; HASHED_PUBKEY_SERIALIZED_SIZE],
+) -> Result<bool, Secp256k1Error> {
+    let digest = eip712::encode_digest(domain_separator, struct_hash);
+    let eth_address = recover_eth_address_from_prehash(&digest, signature, recovery_id)?;
+    Ok(eth_address == *expected_eth_address)
+}
+
+/// Recovers the Ethereum address of the key that signed `message`.
+///
+/// The message is hashed with Keccak-256, as in [`sign_message`], and the
+/// signing public key is recovered from `signature` and `recovery_id`. Unlike
+/// [`verify_eip712_signature`], no expected address is needed; callers can
+/// look the result up, e.g. in an allowlist.
+///
+/// The recovery ID is the raw 0 or 1 value, not Ethereum's `v` value of 27
+/// or 28. Signatures with `r` or `s` equal to zero, or not less than the
+/// curve order, fail with [`Secp256k1Error::InvalidSignature`].
+pub fn recover_eth_address(
+    message: &[u8],
+    signature: &[u8; SIGNATURE_SERIALIZED_SIZE],
+    recovery_id: u8,
+) -> Result<[u8; HASHED_PUBKEY_SERIALIZED_SIZE], Secp256k1Error> {
+    let message_hash: [u8; 32] = sha3::Keccak256::digest(message).into();
+    recover_eth_address_from_prehash(&message_hash, signature, recovery_id)
+}
+
+/// Recovers the public key that signed `message`.
+///
+/// The message is hashed with Keccak-256, as in [`sign_message`], and the
+/// key is returned as the 64-byte uncompressed point without the `0x04`
+/// prefix, the same encoding returned by the `secp256k1_recover` syscall and
+/// accepted by [`eth_address_from_pubkey`].
+///
+/// Unlike the syscall, this rejects signatures with a high `s` value, and
+/// signatures with `r` or `s` equal to zero or not less than the curve
+/// order, with [`Secp256k1Error::InvalidSignature`] or
+/// [`Secp256k1Error::Ecdsa`].
+pub fn recover_pubkey(
+    message: &[u8],
+    signature: &[u8; SIGNATURE_SERIALIZED_SIZE],
+    recovery_id: u8,
+) -> Result<[u8; SECP256K1_PUBKEY_SIZE], Secp256k1Error> {
+    let message_hash: [u8; 32] = sha3::Keccak256::digest(message).into();
+    let verifying_key = recover_verifying_key_from_prehash(&message_hash, signature, recovery_id)?;
+    let encoded_point = verifying_key.to_encoded_point(false);
+    Ok(encoded_point.as_bytes()[1..]
+        .try_into()
+        .expect("uncompressed point is 65 bytes"))
+}
+
+/// Recovers the Ethereum address of the key that signed the 32-byte `prehash`.
+///
+/// Degenerate signatures, where `r` or `s` is zero or not less than the curve
+/// order, are rejected with [`Secp256k1Error::InvalidSignature`] before any
+/// recovery is attempted.
+fn recover_eth_address_from_prehash(
+    prehash: &[u8; 32],
+    signature: &[u8; SIGNATURE_SERIALIZED_SIZE],
+    recovery_id: u8,
+) -> Result<[u8; HASHED_PUBKEY_SERIALIZED_SIZE], Secp256k1Error> {
+    let verifying_key = recover_verifying_key_from_prehash(prehash, signature, recovery_id)?;
+    Ok(eth_address_from_verifying_key(&verifying_key))
+}
+
+/// Recovers the public key that signed the 32-byte `prehash`, rejecting
+/// degenerate signatures like [`recover_eth_address_from_prehash`].
+fn recover_verifying_key_from_prehash(
+    prehash: &[u8; 32],
+    signature: &[u8; SIGNATURE_SERIALIZED_SIZE],
+    recovery_id: u8,
+) -> Result<k256::ecdsa::VerifyingKey, Secp256k1Error> {
+    let signature = k256::ecdsa::Signature::from_slice(signature)
+        .map_err(|_| Secp256k1Error::InvalidSignature)?;
+    let recovery_id =
+        k256::ecdsa::RecoveryId::from_byte(recovery_id).ok_or(Secp256k1Error::InvalidRecoveryId)?;
+    Ok(k256::ecdsa::VerifyingKey::recover_from_prehash(
+        prehash,
+        &signature,
+        recovery_id,
+    )?)
+}
+
+/// Checks a secp256k1 instruction the way the secp256k1 program does.
+///
+/// `data` is the secp256k1 instruction data and `instruction_datas` holds
+/// the data of every instruction in the transaction, in order, so that the
+/// instruction indexes in each [`SecpSignatureOffsets`] can be resolved.
+/// For each signature, the Ethereum address is recovered from the message,
+/// signature and recovery ID and compared with the address in the
+/// instruction data.
+///
+/// Recovery uses `k256`, which rejects signatures with a high `s` value that
+/// the runtime accepts, so this check is slightly stricter than the program.
+/// Signatures from [`sign_message`] always have a low `s`.
+pub fn verify_secp256k1_instruction(
+    data: &[u8],
+    instruction_datas: &[&[u8]],
+) -> Result<(), Secp256k1Error> {
+    let (&num_signatures, offsets_data) = data
+        .split_first()
+        .ok_or(Secp256k1Error::InvalidInstructionDataSize)?;
+    if num_signatures == 0 && !offsets_data.is_empty() {
+        return Err(Secp256k1Error::InvalidInstructionDataSize);
+    }
+    let offsets_data = offsets_data
+        .get(..(num_signatures as usize).saturating_mul(SIGNATURE_OFFSETS_SERIALIZED_SIZE))
+        .ok_or(Secp256k1Error::InvalidInstructionDataSize)?;
+
+    for chunk in offsets_data.chunks_exact(SIGNATURE_OFFSETS_SERIALIZED_SIZE) {
+        let offsets = SecpSignatureOffsets::from_slice(chunk)?;
+
+        // the recovery ID directly follows the signature
+        let signature_instruction = instruction_datas
+            .get(offsets.signature_instruction_index as usize)
+            .ok_or(Secp256k1Error::InvalidInstructionDataSize)?;
+        let signature_start = offsets.signature_offset as usize;
+        let signature_end = signature_start.saturating_add(SIGNATURE_SERIALIZED_SIZE);
+        let signature: &[u8; SIGNATURE_SERIALIZED_SIZE] = signature_instruction
+            .get(signature_start..signature_end)
+            .and_then(|signature| signature.try_into().ok())
+            .ok_or(Secp256k1Error::InvalidSignature)?;
+        let recovery_id = *signature_instruction
+            .get(signature_end)
+            .ok_or(Secp256k1Error::InvalidSignature)?;
+
+        let eth_address = get_data_slice(
+            instruction_datas,
+            offsets.eth_address_instruction_index,
+            offsets.eth_address_offset,
+            HASHED_PUBKEY_SERIALIZED_SIZE,
+        )?;
+        let message = get_data_slice(
+            instruction_datas,
+            offsets.message_instruction_index,
+            offsets.message_data_offset,
+            offsets.message_data_size as usize,
+        )?;
+
+        let recovered_eth_address =
+            recover_eth_address(message, signature, recovery_id).map_err(|err| match err {
+                Secp256k1Error::Ecdsa => Secp256k1Error::InvalidSignature,
+                err => err,
+            })?;
+        if recovered_eth_address != eth_address {
+            return Err(Secp256k1Error::InvalidSignature);
+        }
+    }
+    Ok(())
+}
+
+fn get_data_slice<'a>(
+    instruction_datas: &[&'a [u8]],
+    instruction_index: u8,
+    offset: u16,
+    size: usize,
+) -> Result<&'a [u8], Secp256k1Error> {
+    let instruction = instruction_datas
+        .get(instruction_index as usize)
+        .ok_or(Secp256k1Error::InvalidDataOffsets)?;
+    let start = offset as usize;
+    instruction
+        .get(start..start.saturating_add(size))
+        .ok_or(Secp256k1Error::InvalidSignature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eth_address_from_verifying_key() {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let verifying_key = signing_key.verifying_key();
+
+        let encoded_point = verifying_key.to_encoded_point(false);
+        let pubkey: [u8; SECP256K1_PUBKEY_SIZE] = encoded_point.as_bytes()[1..].try_into().unwrap();
+
+        assert_eq!(
+            eth_address_from_verifying_key(verifying_key),
+            eth_address_from_pubkey(&pubkey)
+        );
+    }
+
+    #[test]
+    fn test_eth_address_to_checksum_string() {
+        // examples from EIP-55
+        for expected in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            let addr: [u8; HASHED_PUBKEY_SERIALIZED_SIZE] =
+                hex::decode(&expected[2..]).unwrap().try_into().unwrap();
+            assert_eq!(eth_address_to_checksum_string(&addr), expected);
+        }
+    }
+
+    #[test]
+    fn test_verify_eip712_signature() {
+        // The `Mail` example from the EIP-712 specification, signed with the
+        // private key `keccak256("cow")`.
+        let domain_separator: [u8; 32] =
+            hex::decode("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let struct_hash: [u8; 32] =
+            hex::decode("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let signature: [u8; SIGNATURE_SERIALIZED_SIZE] = hex::decode(
+            "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d\
+             07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        // v = 28
+        let recovery_id = 1;
+        let eth_address: [u8; HASHED_PUBKEY_SERIALIZED_SIZE] =
+            hex::decode("cd2a3d9f938e13cd947ec05abc7fe734df8dd826")
+                .unwrap()
+                .try_into()
+                .unwrap();
+
+        assert_eq!(
+            eip712::encode_digest(&domain_separator, &struct_hash).as_slice(),
+            hex::decode("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+                .unwrap()
+        );
+        assert!(verify_eip712_signature(
+            &domain_separator,
+            &struct_hash,
+            &signature,
+            recovery_id,
+            &eth_address
+        )
+        .unwrap());
+
+        let mut wrong_struct_hash = struct_hash;
+        wrong_struct_hash[0] ^= 1;
+        assert!(!verify_eip712_signature(
+            &domain_separator,
+            &wrong_struct_hash,
+            &signature,
+            recovery_id,
+            &eth_address
+        )
+        .unwrap());
+
+        assert_eq!(
+            verify_eip712_signature(&domain_separator, &struct_hash, &signature, 4, &eth_address),
+            Err(Secp256k1Error::InvalidRecoveryId)
+        );
+        assert_eq!(
+            verify_eip712_signature(
+                &domain_separator,
+                &struct_hash,
+                &[0; SIGNATURE_SERIALIZED_SIZE],
+                recovery_id,
+                &eth_address
+            ),
+            Err(Secp256k1Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_recover_eth_address() {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let eth_address = eth_address_from_verifying_key(signing_key.verifying_key());
+        let priv_key_bytes: [u8; SECP256K1_PRIVATE_KEY_SIZE] = signing_key.to_bytes().into();
+        let message = b"hello";
+
+        let (signature, recovery_id) = sign_message(&priv_key_bytes, message).unwrap();
+        assert_eq!(
+            recover_eth_address(message, &signature, recovery_id),
+            Ok(eth_address)
+        );
+
+        // a different message recovers some other key
+        assert_ne!(
+            recover_eth_address(b"goodbye", &signature, recovery_id),
+            Ok(eth_address)
+        );
+        assert_eq!(
+            recover_eth_address(message, &signature, 4),
+            Err(Secp256k1Error::InvalidRecoveryId)
+        );
+        assert_eq!(
+            recover_eth_address(message, &[0u8; SIGNATURE_SERIALIZED_SIZE], recovery_id),
+            Err(Secp256k1Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_sign_message_low_s() {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let priv_key_bytes = signing_key.to_bytes().into();
+        let eth_address = eth_address_from_verifying_key(signing_key.verifying_key());
+        for i in 0..64u32 {
+            let message = i.to_le_bytes();
+            let (signature, recovery_id) = sign_message(&priv_key_bytes, &message).unwrap();
+            let parsed = k256::ecdsa::Signature::from_slice(&signature).unwrap();
+            // `normalize_s` only returns a new signature when `s` is high
+            assert!(parsed.normalize_s().is_none());
+            // the recovery ID matches the normalized signature
+            assert_eq!(
+                recover_eth_address(&message, &signature, recovery_id),
+                Ok(eth_address)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sign_messages() {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let priv_key_bytes = signing_key.to_bytes().into();
+        let messages: [&[u8]; 3] = [b"hello", b"", b"hello world"];
+
+        let signatures = sign_messages(&priv_key_bytes, &messages).unwrap();
+        assert_eq!(signatures.len(), messages.len());
+        for (message, signature) in messages.iter().zip(&signatures) {
+            assert_eq!(*signature, sign_message(&priv_key_bytes, message).unwrap());
+        }
+        assert_eq!(sign_messages(&priv_key_bytes, &[]).unwrap(), vec![]);
+        assert!(sign_messages(&[0; SECP256K1_PRIVATE_KEY_SIZE], &messages).is_err());
+    }
+
+    #[test]
+    fn test_sign_prehashed() {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let priv_key_bytes = signing_key.to_bytes().into();
+        let eth_address = eth_address_from_verifying_key(signing_key.verifying_key());
+        let message = b"hello";
+        let message_hash: [u8; 32] = sha3::Keccak256::digest(message).into();
+
+        assert_eq!(
+            sign_prehashed(&priv_key_bytes, &message_hash).unwrap(),
+            sign_message(&priv_key_bytes, message).unwrap()
+        );
+
+        // an EIP-712 digest is signed as is, without hashing it again
+        let domain_separator = [1; 32];
+        let struct_hash = [2; 32];
+        let digest = eip712::encode_digest(&domain_separator, &struct_hash);
+        let (signature, recovery_id) = sign_prehashed(&priv_key_bytes, &digest).unwrap();
+        assert!(verify_eip712_signature(
+            &domain_separator,
+            &struct_hash,
+            &signature,
+            recovery_id,
+            &eth_address
+        )
+        .unwrap());
+
+        assert!(sign_prehashed(&[0; SECP256K1_PRIVATE_KEY_SIZE], &message_hash).is_err());
+    }
+
+    #[test]
+    fn test_recover_pubkey() {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let encoded_point = signing_key.verifying_key().to_encoded_point(false);
+        let pubkey: [u8; SECP256K1_PUBKEY_SIZE] = encoded_point.as_bytes()[1..].try_into().unwrap();
+        let message = b"hello";
+        let (signature, recovery_id) =
+            sign_message(&signing_key.to_bytes().into(), message).unwrap();
+
+        assert_eq!(recover_pubkey(message, &signature, recovery_id), Ok(pubkey));
+        assert_eq!(
+            recover_eth_address(message, &signature, recovery_id),
+            Ok(eth_address_from_pubkey(&pubkey))
+        );
+        assert_ne!(
+            recover_pubkey(b"other message", &signature, recovery_id),
+            Ok(pubkey)
+        );
+        assert_eq!(
+            recover_pubkey(message, &signature, 4),
+            Err(Secp256k1Error::InvalidRecoveryId)
+        );
+        assert_eq!(
+            recover_pubkey(message, &[0; SIGNATURE_SERIALIZED_SIZE], recovery_id),
+            Err(Secp256k1Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_recover_rejects_degenerate_signatures() {
+        // secp256k1 curve order n
+        let n = hex::decode("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141")
+            .unwrap();
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let priv_key_bytes: [u8; SECP256K1_PRIVATE_KEY_SIZE] = signing_key.to_bytes().into();
+        let message = b"hello";
+        let (signature, recovery_id) = sign_message(&priv_key_bytes, message).unwrap();
+        let domain_separator = [1u8; 32];
+        let struct_hash = [2u8; 32];
+        let eth_address = [3u8; HASHED_PUBKEY_SERIALIZED_SIZE];
+
+        let mut zero_r = signature;
+        zero_r[..32].fill(0);
+        let mut zero_s = signature;
+        zero_s[32..].fill(0);
+        let mut order_s = signature;
+        order_s[32..].copy_from_slice(&n);
+        let mut over_order_s = signature;
+        over_order_s[32..].fill(0xff);
+        let mut over_order_r = signature;
+        over_order_r[..32].copy_from_slice(&n);
+
+        for degenerate in [zero_r, zero_s, order_s, over_order_s, over_order_r] {
+            assert_eq!(
+                recover_eth_address(message, &degenerate, recovery_id),
+                Err(Secp256k1Error::InvalidSignature)
+            );
+            assert_eq!(
+                verify_eip712_signature(
+                    &domain_separator,
+                    &struct_hash,
+                    &degenerate,
+                    recovery_id,
+                    &eth_address
+                ),
+                Err(Secp256k1Error::InvalidSignature)
+            );
+        }
+    }
+
+    #[test]
+    fn test_secp256k1_error_from_k256() {
+        let err = k256::ecdsa::SigningKey::from_slice(&[0u8; SECP256K1_PRIVATE_KEY_SIZE])
+            .map_err(Secp256k1Error::from)
+            .unwrap_err();
+        assert_eq!(err, Secp256k1Error::Ecdsa);
+    }
+
+    #[test]
+    fn test_new_secp256k1_instruction_message_too_large() {
+        let signature = [0u8; SIGNATURE_SERIALIZED_SIZE];
+        let eth_address = [0u8; HASHED_PUBKEY_SERIALIZED_SIZE];
+
+        let message = vec![0u8; 70_000];
+        assert_eq!(
+            try_new_secp256k1_instruction_with_signature(&message, &signature, 0, &eth_address),
+            Err(Secp256k1Error::MessageTooLarge)
+        );
+
+        let message = vec![0u8; u16::MAX as usize];
+        let instruction =
+            try_new_secp256k1_instruction_with_signature(&message, &signature, 0, &eth_address)
+                .unwrap();
+        let offsets: SecpSignatureOffsets =
+            bincode::deserialize(&instruction.data[1..DATA_START]).unwrap();
+        assert_eq!(offsets.message_data_size, u16::MAX);
+    }
+
+    #[test]
+    fn test_single_sig_layout() {
+        let message = b"hello";
+        let signature = [7u8; SIGNATURE_SERIALIZED_SIZE];
+        let recovery_id = 1;
+        let eth_address = [9u8; HASHED_PUBKEY_SERIALIZED_SIZE];
+        let data = new_secp256k1_instruction_with_signature(
+            message,
+            &signature,
+            recovery_id,
+            &eth_address,
+        )
+        .data;
+
+        let layout = single_sig_layout(message.len());
+        assert_eq!(layout.data_len, data.len());
+        assert_eq!(
+            &data[layout.eth_address_offset..layout.signature_offset],
+            eth_address
+        );
+        assert_eq!(
+            &data[layout.signature_offset..layout.recovery_id_offset],
+            signature
+        );
+        assert_eq!(data[layout.recovery_id_offset], recovery_id);
+        assert_eq!(&data[layout.message_offset..], message);
+
+        let offsets: SecpSignatureOffsets = bincode::deserialize(&data[1..DATA_START]).unwrap();
+        assert_eq!(
+            offsets.eth_address_offset as usize,
+            layout.eth_address_offset
+        );
+        assert_eq!(offsets.signature_offset as usize, layout.signature_offset);
+        assert_eq!(offsets.message_data_offset as usize, layout.message_offset);
+    }
+
+    #[test]
+    fn test_secp_signature_offsets_from_slice() {
+        let offsets = SecpSignatureOffsets {
+            signature_offset: 0x0102,
+            signature_instruction_index: 3,
+            eth_address_offset: 0x0405,
+            eth_address_instruction_index: 6,
+            message_data_offset: 0x0708,
+            message_data_size: 0x090a,
+            message_instruction_index: 11,
+        };
+        let data = bincode::serialize(&offsets).unwrap();
+        assert_eq!(data.len(), SIGNATURE_OFFSETS_SERIALIZED_SIZE);
+        assert_eq!(SecpSignatureOffsets::from_slice(&data), Ok(offsets));
+
+        let instruction = new_secp256k1_instruction_with_signature(b"hello", &[0; 64], 0, &[0; 20]);
+        let offsets = SecpSignatureOffsets::from_slice(&instruction.data[1..]).unwrap();
+        assert_eq!(
+            offsets,
+            bincode::deserialize(&instruction.data[1..DATA_START]).unwrap()
+        );
+
+        assert_eq!(
+            SecpSignatureOffsets::from_slice(&data[..SIGNATURE_OFFSETS_SERIALIZED_SIZE - 1]),
+            Err(Secp256k1Error::InvalidInstructionDataSize)
+        );
+        assert_eq!(
+            SecpSignatureOffsets::from_slice(&[]),
+            Err(Secp256k1Error::InvalidInstructionDataSize)
+        );
+    }
+
+    #[test]
+    fn test_secp_signature_offsets_validate_against() {
+        let message = b"hello";
+        let data = new_secp256k1_instruction_with_signature(message, &[0; 64], 0, &[0; 20]).data;
+        let offsets = SecpSignatureOffsets::from_slice(&data[1..]).unwrap();
+        assert_eq!(offsets.validate_against(&[data.len()]), Ok(()));
+        assert_eq!(offsets.validate_against(&[data.len(), 0]), Ok(()));
+        assert_eq!(
+            offsets.validate_against(&[data.len() - 1]),
+            Err(Secp256k1Error::InvalidDataOffsets)
+        );
+        assert_eq!(
+            offsets.validate_against(&[]),
+            Err(Secp256k1Error::InvalidDataOffsets)
+        );
+
+        // each field is checked against its own instruction
+        let layout = single_sig_layout(message.len());
+        let split = SecpSignatureOffsets {
+            signature_offset: 0,
+            signature_instruction_index: 1,
+            eth_address_offset: 0,
+            eth_address_instruction_index: 2,
+            message_data_offset: 0,
+            message_data_size: message.len() as u16,
+            message_instruction_index: 0,
+        };
+        let lens = [message.len(), SIGNATURE_SERIALIZED_SIZE + 1, 20];
+        assert_eq!(split.validate_against(&lens), Ok(()));
+        for i in 0..lens.len() {
+            let mut short = lens;
+            short[i] -= 1;
+            assert_eq!(
+                split.validate_against(&short),
+                Err(Secp256k1Error::InvalidDataOffsets)
+            );
+        }
+        assert_eq!(
+            split.validate_against(&lens[..2]),
+            Err(Secp256k1Error::InvalidDataOffsets)
+        );
+
+        let overflowing = SecpSignatureOffsets {
+            message_data_offset: u16::MAX,
+            message_data_size: u16::MAX,
+            ..SecpSignatureOffsets::from_slice(&data[1..]).unwrap()
+        };
+        assert_eq!(
+            overflowing.validate_against(&[layout.data_len]),
+            Err(Secp256k1Error::InvalidDataOffsets)
+        );
+    }
+
+    #[test]
+    fn test_verify_secp256k1_instruction() {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let eth_address = eth_address_from_verifying_key(signing_key.verifying_key());
+        let message = b"hello";
+        let (signature, recovery_id) =
+            sign_message(&signing_key.to_bytes().into(), message).unwrap();
+        let data = new_secp256k1_instruction_with_signature(
+            message,
+            &signature,
+            recovery_id,
+            &eth_address,
+        )
+        .data;
+        assert_eq!(verify_secp256k1_instruction(&data, &[&data]), Ok(()));
+        assert_eq!(verify_secp256k1_instruction(&[0], &[]), Ok(()));
+
+        // the secp256k1 instruction need not be the first one
+        let mut offsets = SecpSignatureOffsets::from_slice(&data[1..]).unwrap();
+        offsets.signature_instruction_index = 1;
+        offsets.eth_address_instruction_index = 1;
+        offsets.message_instruction_index = 1;
+        let mut moved = data.clone();
+        moved[1..DATA_START].copy_from_slice(&bincode::serialize(&offsets).unwrap());
+        assert_eq!(verify_secp256k1_instruction(&moved, &[&[], &moved]), Ok(()));
+        assert_eq!(
+            verify_secp256k1_instruction(&moved, &[&moved]),
+            Err(Secp256k1Error::InvalidInstructionDataSize)
+        );
+        offsets.signature_instruction_index = 0;
+        moved[1..DATA_START].copy_from_slice(&bincode::serialize(&offsets).unwrap());
+        assert_eq!(
+            verify_secp256k1_instruction(&moved, &[&moved]),
+            Err(Secp256k1Error::InvalidDataOffsets)
+        );
+
+        let layout = single_sig_layout(message.len());
+        let mut wrong_address = data.clone();
+        wrong_address[layout.eth_address_offset] ^= 1;
+        assert_eq!(
+            verify_secp256k1_instruction(&wrong_address, &[&wrong_address]),
+            Err(Secp256k1Error::InvalidSignature)
+        );
+        let mut wrong_message = data.clone();
+        wrong_message[layout.message_offset] ^= 1;
+        assert_eq!(
+            verify_secp256k1_instruction(&wrong_message, &[&wrong_message]),
+            Err(Secp256k1Error::InvalidSignature)
+        );
+        let mut wrong_recovery_id = data.clone();
+        wrong_recovery_id[layout.recovery_id_offset] = 4;
+        assert_eq!(
+            verify_secp256k1_instruction(&wrong_recovery_id, &[&wrong_recovery_id]),
+            Err(Secp256k1Error::InvalidRecoveryId)
+        );
+        let truncated = &data[..data.len() - 1];
+        assert_eq!(
+            verify_secp256k1_instruction(truncated, &[truncated]),
+            Err(Secp256k1Error::InvalidSignature)
+        );
+
+        assert_eq!(
+            verify_secp256k1_instruction(&[], &[]),
+            Err(Secp256k1Error::InvalidInstructionDataSize)
+        );
+        assert_eq!(
+            verify_secp256k1_instruction(&[0, 0], &[]),
+            Err(Secp256k1Error::InvalidInstructionDataSize)
+        );
+        assert_eq!(
+            verify_secp256k1_instruction(&data[..DATA_START - 1], &[&data]),
+            Err(Secp256k1Error::InvalidInstructionDataSize)
+        );
+    }
+
+    #[test]
+    fn test_secp256k1_instruction_builder() {
+        let signatures: Vec<_> = (0..3)
+            .map(|i| {
+                let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+                let eth_address = eth_address_from_verifying_key(signing_key.verifying_key());
+                let message = format!("hello world {i}").into_bytes();
+                let (signature, recovery_id) =
+                    sign_message(&signing_key.to_bytes().into(), &message).unwrap();
+                (message, signature, recovery_id, eth_address)
+            })
+            .collect();
+        let builder = signatures.iter().fold(
+            Secp256k1InstructionBuilder::new(),
+            |builder, (message, signature, recovery_id, eth_address)| {
+                builder.add_signature(message, signature, *recovery_id, eth_address)
+            },
+        );
+
+        let instruction = builder.build();
+        assert_eq!(
+            instruction.program_id,
+            solana_sdk_ids::secp256k1_program::id()
+        );
+        assert_eq!(instruction.data[0], 3);
+        assert_eq!(
+            verify_secp256k1_instruction(&instruction.data, &[&instruction.data]),
+            Ok(())
+        );
+        let offsets = SecpSignatureOffsets::from_slice(
+            &instruction.data[1 + SIGNATURE_OFFSETS_SERIALIZED_SIZE..],
+        )
+        .unwrap();
+        let (message, signature, recovery_id, eth_address) = &signatures[1];
+        let signature_offset = offsets.signature_offset as usize;
+        assert_eq!(
+            &instruction.data[signature_offset..signature_offset + SIGNATURE_SERIALIZED_SIZE],
+            signature
+        );
+        assert_eq!(
+            instruction.data[signature_offset + SIGNATURE_SERIALIZED_SIZE],
+            *recovery_id
+        );
+        let eth_address_offset = offsets.eth_address_offset as usize;
+        assert_eq!(
+            &instruction.data
+                [eth_address_offset..eth_address_offset + HASHED_PUBKEY_SERIALIZED_SIZE],
+            eth_address
+        );
+        let message_data_offset = offsets.message_data_offset as usize;
+        assert_eq!(
+            &instruction.data
+                [message_data_offset..message_data_offset + offsets.message_data_size as usize],
+            message
+        );
+
+        // the offsets refer to the configured instruction index
+        let instruction = builder.clone().instruction_index(1).build();
+        assert_eq!(
+            verify_secp256k1_instruction(&instruction.data, &[&[], &instruction.data]),
+            Ok(())
+        );
+
+        let empty = Secp256k1InstructionBuilder::new().build();
+        assert_eq!(empty.data, vec![0]);
+
+        let (message, signature, recovery_id, eth_address) = &signatures[0];
+        let too_many = (0..256).fold(Secp256k1InstructionBuilder::new(), |builder, _| {
+            builder.add_signature(message, signature, *recovery_id, eth_address)
+        });
+        assert_eq!(too_many.try_build(), Err(Secp256k1Error::TooManySignatures));
+        let too_large = Secp256k1InstructionBuilder::new().add_signature(
+            &vec![0; u16::MAX as usize + 1],
+            signature,
+            *recovery_id,
+            eth_address,
+        );
+        assert_eq!(too_large.try_build(), Err(Secp256k1Error::MessageTooLarge));
+        let overflowing_offsets = Secp256k1InstructionBuilder::new()
+            .add_signature(
+                &vec![0; u16::MAX as usize],
+                signature,
+                *recovery_id,
+                eth_address,
+            )
+            .add_signature(message, signature, *recovery_id, eth_address);
+        assert_eq!(
+            overflowing_offsets.try_build(),
+            Err(Secp256k1Error::InstructionDataTooLarge)
+        );
+    }
+}
@@ -154,9 +154,13 @@
 //! # Ok::<(), anyhow::Error>(())
 //! ```
 
-#[cfg(feature = "bincode")]
-use crate::SysvarSerialize;
 use crate::{impl_sysvar_get, Sysvar};
+#[cfg(feature = "bincode")]
+use {
+    crate::{from_account_info_checked_len, SysvarSerialize},
+    solana_account_info::AccountInfo,
+    solana_program_error::ProgramError,
+};
 pub use {
     solana_epoch_rewards::EpochRewards,
     solana_sdk_ids::sysvar::epoch_rewards::{check_id, id, ID},
@@ -167,4 +171,9 @@ impl Sysvar for EpochRewards {
 }
 
 #[cfg(feature = "bincode")]
-impl SysvarSerialize for EpochRewards {}
+impl SysvarSerialize for EpochRewards {
+    // override
+    fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        from_account_info_checked_len(account_info)
+    }
+}
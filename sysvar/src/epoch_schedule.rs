@@ -119,9 +119,13 @@
 //! #
 //! # Ok::<(), anyhow::Error>(())
 //! ```
-#[cfg(feature = "bincode")]
-use crate::SysvarSerialize;
 use crate::{impl_sysvar_get, Sysvar};
+#[cfg(feature = "bincode")]
+use {
+    crate::{from_account_info_checked_len, SysvarSerialize},
+    solana_account_info::AccountInfo,
+    solana_program_error::ProgramError,
+};
 pub use {
     solana_epoch_schedule::EpochSchedule,
     solana_sdk_ids::sysvar::epoch_schedule::{check_id, id, ID},
@@ -132,4 +136,9 @@ impl Sysvar for EpochSchedule {
 }
 
 #[cfg(feature = "bincode")]
-impl SysvarSerialize for EpochSchedule {}
+impl SysvarSerialize for EpochSchedule {
+    // override
+    fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        from_account_info_checked_len(account_info)
+    }
+}
@@ -83,6 +83,8 @@ pub mod __private {
     pub use solana_define_syscall::definitions;
     pub use {solana_program_entrypoint::SUCCESS, solana_program_error::ProgramError};
 }
+#[cfg(feature = "get-with-clock")]
+use solana_clock::{Clock, Slot};
 #[cfg(feature = "bincode")]
 use {solana_account_info::AccountInfo, solana_sysvar_id::SysvarId};
 use {solana_program_error::ProgramError, solana_pubkey::Pubkey};
@@ -123,6 +125,19 @@ pub trait Sysvar: Default + Sized {
     fn get() -> Result<Self, ProgramError> {
         Err(ProgramError::UnsupportedSysvar)
     }
+
+    /// Load the sysvar directly from the runtime, together with the slot it
+    /// was read at.
+    ///
+    /// The slot comes from the [`Clock`] sysvar, so this costs one extra
+    /// syscall compared to [`Sysvar::get`]. It lets callers that cache
+    /// sysvars key the cached value by slot.
+    #[cfg(feature = "get-with-clock")]
+    fn get_with_clock() -> Result<(Self, Slot), ProgramError> {
+        let sysvar = Self::get()?;
+        let clock = Clock::get()?;
+        Ok((sysvar, clock.slot))
+    }
 }
 
 #[cfg(feature = "bincode")]
@@ -158,6 +173,29 @@ pub trait SysvarSerialize:
     }
 }
 
+/// Deserializes a fixed-size sysvar from its `AccountInfo`, rejecting account
+/// data whose length differs from [`SysvarSerialize::size_of`].
+///
+/// # Errors
+///
+/// If `account_info` does not have the same ID as the sysvar this function
+/// returns [`ProgramError::InvalidArgument`]. If the account data is shorter
+/// or longer than the serialized sysvar it returns
+/// [`ProgramError::InvalidAccountData`].
+#[cfg(feature = "bincode")]
+pub(crate) fn from_account_info_checked_len<T: SysvarSerialize>(
+    account_info: &AccountInfo,
+) -> Result<T, ProgramError> {
+    if !T::check_id(account_info.unsigned_key()) {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let data = account_info.data.borrow();
+    if data.len() != T::size_of() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    bincode::deserialize(&data).map_err(|_| ProgramError::InvalidArgument)
+}
+
 /// Implements the [`Sysvar::get`] method for both SBF and host targets.
 #[macro_export]
 macro_rules! impl_sysvar_get {
@@ -294,4 +332,81 @@ mod tests {
         account_info.data = Rc::new(RefCell::new(&mut small_data));
         assert_eq!(test_sysvar.to_account_info(&mut account_info), None);
     }
+
+    fn check_from_account_info_rejects_wrong_len<T>()
+    where
+        T: SysvarSerialize + PartialEq + std::fmt::Debug,
+    {
+        let key = T::id();
+        let owner = Pubkey::new_unique();
+        let mut lamports = 42;
+        let mut data = vec![0_u8; T::size_of()];
+        let mut account_info =
+            AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false);
+        T::default().to_account_info(&mut account_info).unwrap();
+        assert_eq!(T::from_account_info(&account_info), Ok(T::default()));
+
+        let mut truncated_data = vec![0_u8; T::size_of().saturating_sub(1)];
+        account_info.data = Rc::new(RefCell::new(&mut truncated_data));
+        assert_eq!(
+            T::from_account_info(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let mut over_long_data = vec![0_u8; T::size_of().saturating_add(1)];
+        account_info.data = Rc::new(RefCell::new(&mut over_long_data));
+        assert_eq!(
+            T::from_account_info(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_from_account_info_rejects_wrong_len() {
+        check_from_account_info_rejects_wrong_len::<rent::Rent>();
+        check_from_account_info_rejects_wrong_len::<epoch_schedule::EpochSchedule>();
+        check_from_account_info_rejects_wrong_len::<epoch_rewards::EpochRewards>();
+    }
+
+    // NOTE tests that use this mock MUST carry the #[serial] attribute
+    #[cfg(feature = "get-with-clock")]
+    struct MockClockAndRentSyscalls {
+        clock: Clock,
+        rent: rent::Rent,
+    }
+    #[cfg(feature = "get-with-clock")]
+    impl SyscallStubs for MockClockAndRentSyscalls {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut _ as *mut Clock) = self.clock.clone() };
+            SUCCESS
+        }
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut _ as *mut rent::Rent) = self.rent.clone() };
+            SUCCESS
+        }
+    }
+
+    #[cfg(feature = "get-with-clock")]
+    #[test]
+    #[serial_test::serial]
+    fn test_get_with_clock() {
+        let clock = Clock {
+            slot: 42,
+            ..Clock::default()
+        };
+        let rent = rent::Rent {
+            lamports_per_byte_year: 7,
+            ..rent::Rent::default()
+        };
+        set_syscall_stubs(Box::new(MockClockAndRentSyscalls {
+            clock,
+            rent: rent.clone(),
+        }));
+
+        assert_eq!(rent::Rent::get_with_clock(), Ok((rent, 42)));
+        assert_eq!(
+            epoch_schedule::EpochSchedule::get_with_clock(),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
 }
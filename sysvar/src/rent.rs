@@ -121,9 +121,13 @@
 //! #
 //! # Ok::<(), anyhow::Error>(())
 //! ```
-#[cfg(feature = "bincode")]
-use crate::SysvarSerialize;
 use crate::{impl_sysvar_get, Sysvar};
+#[cfg(feature = "bincode")]
+use {
+    crate::{from_account_info_checked_len, SysvarSerialize},
+    solana_account_info::AccountInfo,
+    solana_program_error::ProgramError,
+};
 pub use {
     solana_rent::Rent,
     solana_sdk_ids::sysvar::rent::{check_id, id, ID},
@@ -133,4 +137,9 @@ impl Sysvar for Rent {
 }
 
 #[cfg(feature = "bincode")]
-impl SysvarSerialize for Rent {}
+impl SysvarSerialize for Rent {
+    // override
+    fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        from_account_info_checked_len(account_info)
+    }
+}